@@ -0,0 +1,6 @@
+/*!
+
+This crate only exists to run the `forward-traits` examples under the 2021
+edition, as tests.
+
+*/
@@ -20,6 +20,12 @@ trait Foo: Sized
 	fn foo12 () -> Result <Self, ()>;
 	fn foo13 () -> (Self, Self);
 	fn foo14 () -> [Self; 2];
+
+	fn foo15 (x: Result <(), Self>);
+	fn foo16 (x: Result <Self, Self>) -> Result <Self, Self>;
+	fn foo17 (x: std::task::Poll <Option <Self>>) -> std::task::Poll <Option <Self>>;
+	fn foo18 (x: std::ops::ControlFlow <Self, Self>) -> std::ops::ControlFlow <Self, Self>;
+	fn foo19 (x: std::ops::Bound <Self>) -> std::ops::Bound <Self>;
 }
 
 #[forwardable]
@@ -33,6 +39,10 @@ trait Bar: Sized
 	fn bar6 (x: Result <Self, ()>);
 	fn bar7 (x: (Self, Self));
 	fn bar8 (x: [Self; 2]);
+	fn bar9 (x: Result <(), Self>);
+	fn bar10 (x: std::task::Poll <Option <Self>>);
+	fn bar11 (x: std::ops::ControlFlow <Self>);
+	fn bar12 (x: std::ops::Bound <&Self>);
 }
 
 struct A;
@@ -54,6 +64,12 @@ impl Foo for A
 	fn foo12 () -> Result <Self, ()> { Ok (A) }
 	fn foo13 () -> (Self, Self) { (A, A) }
 	fn foo14 () -> [Self; 2] { [A, A] }
+
+	fn foo15 (_x: Result <(), Self>) {}
+	fn foo16 (x: Result <Self, Self>) -> Result <Self, Self> { x }
+	fn foo17 (x: std::task::Poll <Option <Self>>) -> std::task::Poll <Option <Self>> { x }
+	fn foo18 (x: std::ops::ControlFlow <Self, Self>) -> std::ops::ControlFlow <Self, Self> { x }
+	fn foo19 (x: std::ops::Bound <Self>) -> std::ops::Bound <Self> { x }
 }
 
 impl Bar for A
@@ -66,6 +82,10 @@ impl Bar for A
 	fn bar6 (_x: Result <Self, ()>) {}
 	fn bar7 (_x: (Self, Self)) {}
 	fn bar8 (_x: [Self; 2]) {}
+	fn bar9 (_x: Result <(), Self>) {}
+	fn bar10 (_x: std::task::Poll <Option <Self>>) {}
+	fn bar11 (_x: std::ops::ControlFlow <Self>) {}
+	fn bar12 (_x: std::ops::Bound <&Self>) {}
 }
 
 #[forward_receiver]
@@ -116,4 +136,11 @@ fn main ()
 
 	let b = B::foo10 ();
 	b . foo4 ();
+
+	assert! (B::foo16 (Err (B (A))) . is_err ());
+	assert! (B::foo17 (std::task::Poll::Ready (Some (B (A)))) . is_ready ());
+	assert! (B::foo18 (std::ops::ControlFlow::Break (B (A))) . is_break ());
+
+	B::bar9 (Err (B (A)));
+	B::bar12 (std::ops::Bound::Included (&B (A)));
 }
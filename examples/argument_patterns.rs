@@ -0,0 +1,60 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[derive (Clone, Copy)]
+struct Point
+{
+	x: i32,
+	y: i32
+}
+
+// Patterns other than plain identifiers are only allowed in methods with
+// bodies, so these methods all have default implementations.
+#[forwardable]
+trait Geometry
+{
+	fn shift (&mut self, mut dx: i32, (a, b): (i32, i32))
+	{
+		dx += a + b;
+		let _ = dx;
+	}
+
+	fn contains (&self, Point {x, y}: Point) -> bool
+	{
+		x == y
+	}
+
+	fn ignore (&self, _: u32, _: u32) -> u32
+	{
+		0
+	}
+
+	fn shadow (&self, arg_0: u32, arg_1: u32) -> u32
+	{
+		arg_0 - arg_1
+	}
+}
+
+struct Shape;
+
+impl Geometry for Shape
+{
+	fn ignore (&self, a: u32, b: u32) -> u32
+	{
+		a + b
+	}
+}
+
+#[forward_receiver]
+struct Wrapper (Shape);
+
+forward_traits! (for Wrapper . 0 impl Geometry);
+
+fn main ()
+{
+	let mut w = Wrapper (Shape);
+
+	w . shift (1, (2, 3));
+	assert! (w . contains (Point {x: 1, y: 1}));
+	assert_eq! (w . ignore (1, 2), 3);
+	assert_eq! (w . shadow (3, 1), 2);
+}
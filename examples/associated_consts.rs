@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Bounded: Sized + 'static
+{
+	const MIN: Self;
+	const RANGE: (Self, Self);
+	const DEFAULT: Option <Self>;
+	const STEPS: [Self; 3];
+	const ORIGIN: &'static Self;
+	const NAME: &'static str;
+}
+
+impl Bounded for i32
+{
+	const MIN: Self = i32::MIN;
+	const RANGE: (Self, Self) = (-10, 10);
+	const DEFAULT: Option <Self> = Some (0);
+	const STEPS: [Self; 3] = [1, 2, 3];
+	const ORIGIN: &'static Self = &0;
+	const NAME: &'static str = "i32";
+}
+
+// Arrays of and references to constants are reinterpreted, which requires the
+// receiver to be transparent over the delegated member.
+#[derive (Debug, PartialEq)]
+#[repr (transparent)]
+#[forward_receiver]
+struct Meters (i32);
+
+forward_traits! (for Meters . 0 impl Bounded);
+
+#[forwardable]
+trait Zero: Sized
+{
+	const ZERO: Self;
+	const ZEROS: (Self, Option <Self>);
+}
+
+impl Zero for i32
+{
+	const ZERO: Self = 0;
+	const ZEROS: (Self, Option <Self>) = (0, None);
+}
+
+// Owned constants only need the other members to be `PhantomData`.
+#[derive (Debug, PartialEq)]
+#[forward_receiver]
+struct Tagged <T>
+{
+	value: i32,
+	tag: PhantomData <T>
+}
+
+forward_traits! (for Tagged . value impl Zero);
+
+fn main ()
+{
+	assert_eq! (Meters::MIN, Meters (i32::MIN));
+	assert_eq! (Meters::RANGE, (Meters (-10), Meters (10)));
+	assert_eq! (Meters::DEFAULT, Some (Meters (0)));
+	assert_eq! (Meters::STEPS, [Meters (1), Meters (2), Meters (3)]);
+	assert_eq! (Meters::ORIGIN, &Meters (0));
+	assert_eq! (Meters::NAME, "i32");
+
+	assert_eq! (Tagged::<()>::ZERO, Tagged {value: 0, tag: PhantomData});
+	assert_eq! (Tagged::<()>::ZEROS, (Tagged {value: 0, tag: PhantomData}, None));
+}
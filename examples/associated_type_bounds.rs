@@ -0,0 +1,76 @@
+use std::fmt::Debug;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Source
+{
+	type Item: Clone + Debug;
+	type View <'a>: Debug
+	where Self: 'a;
+
+	fn first (&self) -> Self::Item;
+	fn view (&self) -> Self::View <'_>;
+}
+
+impl Source for Vec <u32>
+{
+	type Item = u32;
+	type View <'a> = &'a [u32];
+
+	fn first (&self) -> Self::Item
+	{
+		self [0]
+	}
+
+	fn view (&self) -> Self::View <'_>
+	{
+		self . as_slice ()
+	}
+}
+
+#[derive (Clone, Debug, PartialEq)]
+struct Id (u32);
+
+impl From <u32> for Id
+{
+	fn from (x: u32) -> Self
+	{
+		Self (x)
+	}
+}
+
+#[derive (Debug, PartialEq)]
+struct Shown <'a> (&'a [u32]);
+
+impl <'a> From <&'a [u32]> for Shown <'a>
+{
+	fn from (x: &'a [u32]) -> Self
+	{
+		Self (x)
+	}
+}
+
+#[forward_receiver]
+struct Numbers (Vec <u32>);
+
+// The replacements must satisfy the bounds declared on the associated types
+// (`Id: Clone + Debug` and `for <'a> Shown <'a>: Debug`).  If they don't, the
+// error points at the replacement here.
+forward_traits!
+(
+	for Numbers . 0
+	[
+		Self::Item -> Id,
+		for <'a> Self::View <'a> -> Shown <'a>
+	]
+	impl Source
+);
+
+fn main ()
+{
+	let numbers = Numbers (vec! [1, 2, 3]);
+
+	assert_eq! (numbers . first (), Id (1));
+	assert_eq! (numbers . view (), Shown (&[1, 2, 3]));
+}
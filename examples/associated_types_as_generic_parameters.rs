@@ -57,5 +57,5 @@ fn main ()
 {
 	let mut acc = Wrap::<usize>::zero_accumulator ();
 	Wrap::<usize>::accumulate (&mut acc, 1);
-	assert_eq! (Wrap::<usize>::convert (acc) . 0, 0usize);
+	assert_eq! (Wrap::<usize>::convert (acc) . 0, 1usize);
 }
@@ -0,0 +1,117 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Visit: Sized
+{
+	fn with_mut (&mut self, f: impl FnOnce (&mut Self));
+	fn visit (&self, visitor: &mut dyn FnMut (&Self));
+	fn visit_boxed (&self, visitor: Box <dyn Fn (&Self) -> u32>) -> u32;
+	fn step (self, f: impl FnMut (Self) -> Self) -> Self;
+	fn apply (self, cb: fn (Self) -> Self) -> Self;
+}
+
+#[forwardable]
+trait Consume: Sized
+{
+	fn consume (self, f: impl FnOnce (Self));
+}
+
+#[derive (Debug, PartialEq)]
+struct Counter (u32);
+
+impl Visit for Counter
+{
+	fn with_mut (&mut self, f: impl FnOnce (&mut Self))
+	{
+		f (self)
+	}
+
+	fn visit (&self, visitor: &mut dyn FnMut (&Self))
+	{
+		visitor (self);
+		visitor (self);
+	}
+
+	fn visit_boxed (&self, visitor: Box <dyn Fn (&Self) -> u32>) -> u32
+	{
+		visitor (self)
+	}
+
+	fn step (self, mut f: impl FnMut (Self) -> Self) -> Self
+	{
+		let once = f (self);
+		f (once)
+	}
+
+	fn apply (self, cb: fn (Self) -> Self) -> Self
+	{
+		cb (self)
+	}
+}
+
+impl Consume for Counter
+{
+	fn consume (self, f: impl FnOnce (Self))
+	{
+		f (self)
+	}
+}
+
+// Member delegation to a `#[repr (transparent)]` receiver can turn references
+// to the member back into references to the receiver.
+#[derive (Debug, PartialEq)]
+#[repr (transparent)]
+#[forward_receiver]
+struct Transparent (Counter);
+
+forward_traits! (for Transparent . 0 impl Visit);
+
+// Conversion delegation can hand owned values back to callbacks through `From`.
+#[derive (Debug, PartialEq)]
+#[forward_receiver]
+struct Converted (u32);
+
+impl From <Converted> for Counter
+{
+	fn from (converted: Converted) -> Self
+	{
+		Counter (converted . 0)
+	}
+}
+
+impl From <Counter> for Converted
+{
+	fn from (counter: Counter) -> Self
+	{
+		Converted (counter . 0)
+	}
+}
+
+forward_traits! (for Converted -> Counter impl Consume);
+
+fn increment (t: Transparent) -> Transparent
+{
+	Transparent (Counter (t . 0 . 0 + 1))
+}
+
+fn main ()
+{
+	let mut t = Transparent (Counter (1));
+
+	t . with_mut (|t: &mut Transparent| t . 0 . 0 += 1);
+	assert_eq! (t, Transparent (Counter (2)));
+
+	let mut visits = 0;
+	t . visit (&mut |_: &Transparent| visits += 1);
+	assert_eq! (visits, 2);
+
+	assert_eq! (t . visit_boxed (Box::new (|t: &Transparent| t . 0 . 0)), 2);
+
+	let t = t . step (increment);
+	assert_eq! (t, Transparent (Counter (4)));
+
+	let t = t . apply (increment);
+	assert_eq! (t, Transparent (Counter (5)));
+
+	Converted (7) . consume (|c: Converted| assert_eq! (c, Converted (7)));
+}
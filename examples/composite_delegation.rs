@@ -0,0 +1,151 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Observer
+{
+	fn notify (&mut self, event: &str);
+
+	fn pending (&self) -> usize;
+
+	fn is_idle (&self) -> bool;
+
+	fn flush (&mut self) -> Result <usize, String>;
+
+	fn names (&self) -> Vec <String>;
+}
+
+struct Log
+{
+	name: &'static str,
+	events: Vec <String>,
+	capacity: usize
+}
+
+impl Log
+{
+	fn new (name: &'static str, capacity: usize) -> Self
+	{
+		Self {name, events: Vec::new (), capacity}
+	}
+}
+
+impl Observer for Log
+{
+	fn notify (&mut self, event: &str)
+	{
+		self . events . push (event . to_string ());
+	}
+
+	fn pending (&self) -> usize
+	{
+		self . events . len ()
+	}
+
+	fn is_idle (&self) -> bool
+	{
+		self . events . is_empty ()
+	}
+
+	fn flush (&mut self) -> Result <usize, String>
+	{
+		if self . events . len () > self . capacity
+		{
+			return Err (format! ("{} is full", self . name));
+		}
+
+		Ok (self . events . drain (..) . count ())
+	}
+
+	fn names (&self) -> Vec <String>
+	{
+		vec! [self . name . to_string ()]
+	}
+}
+
+fn concat (names: impl Iterator <Item = Vec <String>>) -> Vec <String>
+{
+	names . flatten () . collect ()
+}
+
+// Every element is flushed, and the first success is kept.  There may be no
+// elements at all, so element delegation can't use `first_ok`.
+fn first_flushed (results: impl Iterator <Item = Result <usize, String>>)
+-> Result <usize, String>
+{
+	results . reduce (Result::or) . unwrap_or (Ok (0))
+}
+
+// Every method is called on both members.  Methods that return values name a
+// reducer for their results, either by method or by return type.
+#[forward_receiver]
+struct Tee
+{
+	a: Log,
+	b: Log
+}
+
+forward_traits!
+(
+	for Tee . (a, b) reduce {pending => sum, -> bool => all, flush => first_ok, names => concat}
+	impl Observer
+);
+
+// Every method is called on each of the elements of the member.
+#[forward_receiver]
+struct Fanout
+{
+	logs: Vec <Log>
+}
+
+forward_traits!
+(
+	for Fanout . [logs] reduce {pending => sum, -> bool => any, flush => first_flushed, -> Vec <String> => concat}
+	impl Observer
+);
+
+fn main ()
+{
+	let mut tee = Tee {a: Log::new ("a", 1), b: Log::new ("b", 2)};
+
+	assert! (tee . is_idle ());
+
+	tee . notify ("first");
+	tee . notify ("second");
+
+	assert_eq! (tee . a . events, ["first", "second"]);
+	assert_eq! (tee . b . events, ["first", "second"]);
+	assert_eq! (tee . pending (), 4);
+	assert! (! tee . is_idle ());
+
+	// `a` is over capacity, but `b` flushes.
+	assert_eq! (tee . flush (), Ok (2));
+	assert_eq! (tee . a . pending (), 2);
+	assert_eq! (tee . b . pending (), 0);
+
+	// Once `b` is over capacity as well, its error is returned.
+	tee . b . events = vec! ["x" . to_string (); 3];
+
+	assert_eq! (tee . flush (), Err ("b is full" . to_string ()));
+	assert_eq! (tee . b . pending (), 3);
+
+	// Once `a` flushes, `b` isn't called at all.
+	tee . a . events . clear ();
+
+	assert_eq! (tee . flush (), Ok (0));
+	assert_eq! (tee . b . pending (), 3);
+	assert_eq! (tee . names (), ["a", "b"]);
+
+	let mut fanout = Fanout {logs: vec! [Log::new ("x", 0), Log::new ("y", 0), Log::new ("z", 1)]};
+
+	fanout . notify ("event");
+
+	assert_eq! (fanout . pending (), 3);
+	assert! (! fanout . is_idle ());
+	assert_eq! (fanout . flush (), Ok (1));
+	assert_eq! (fanout . names (), ["x", "y", "z"]);
+
+	fanout . logs [0] . events . clear ();
+	fanout . logs [1] . events . clear ();
+
+	assert! (fanout . is_idle ());
+}
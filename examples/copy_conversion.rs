@@ -0,0 +1,110 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Vector
+{
+	fn norm_squared (&self) -> f32;
+	fn scale (&mut self, k: f32);
+}
+
+impl Vector for [f32; 2]
+{
+	fn norm_squared (&self) -> f32
+	{
+		self [0] * self [0] + self [1] * self [1]
+	}
+
+	fn scale (&mut self, k: f32)
+	{
+		self [0] *= k;
+		self [1] *= k;
+	}
+}
+
+// Point is laid out differently from [f32; 2], so it can't implement AsRef or
+// AsMut to it, but it can be converted by value.
+#[derive (Clone, Copy, Debug, PartialEq)]
+#[forward_receiver]
+struct Point
+{
+	x: f32,
+	y: f32
+}
+
+impl From <Point> for [f32; 2]
+{
+	fn from (p: Point) -> Self
+	{
+		[p . x, p . y]
+	}
+}
+
+impl From <[f32; 2]> for Point
+{
+	fn from (a: [f32; 2]) -> Self
+	{
+		Self {x: a [0], y: a [1]}
+	}
+}
+
+forward_traits! (for Point copy -> [f32; 2] impl Vector);
+
+#[forwardable]
+trait Shout
+{
+	fn volume (&self) -> usize;
+	fn shout (&mut self);
+}
+
+impl Shout for String
+{
+	fn volume (&self) -> usize
+	{
+		self . chars () . filter (|c| c . is_uppercase ()) . count ()
+	}
+
+	fn shout (&mut self)
+	{
+		*self = self . to_uppercase ();
+	}
+}
+
+#[derive (Clone, Debug, PartialEq)]
+#[forward_receiver]
+struct Label (String);
+
+impl From <Label> for String
+{
+	fn from (label: Label) -> Self
+	{
+		label . 0
+	}
+}
+
+impl From <String> for Label
+{
+	fn from (s: String) -> Self
+	{
+		Self (s)
+	}
+}
+
+forward_traits! (for Label clone -> String impl Shout);
+
+fn main ()
+{
+	let mut p = Point {x: 3.0, y: 4.0};
+
+	assert_eq! (p . norm_squared (), 25.0);
+
+	p . scale (2.0);
+	assert_eq! (p, Point {x: 6.0, y: 8.0});
+
+	let mut label = Label (String::from ("hello"));
+
+	assert_eq! (label . volume (), 0);
+
+	label . shout ();
+	assert_eq! (label, Label (String::from ("HELLO")));
+	assert_eq! (label . volume (), 5);
+}
@@ -0,0 +1,126 @@
+use std::cell::Cell;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+// Not `Clone`, so it can only be passed to one backend.
+struct Ticket (String);
+
+#[forwardable]
+trait Store
+{
+	fn get (&self, key: &str) -> Option <String>;
+
+	fn put (&mut self, key: String, value: String) -> Result <(), String>;
+
+	fn len (&self) -> usize;
+
+	fn redeem (&self, ticket: Ticket) -> Option <String>;
+
+	fn into_keys (self) -> Result <Vec <String>, String>;
+}
+
+struct Backend
+{
+	entries: Vec <(String, String)>,
+	online: bool,
+	calls: Cell <usize>
+}
+
+impl Backend
+{
+	fn new (online: bool) -> Self
+	{
+		Self {entries: Vec::new (), online, calls: Cell::new (0)}
+	}
+}
+
+impl Store for Backend
+{
+	fn get (&self, key: &str) -> Option <String>
+	{
+		self . calls . set (self . calls . get () + 1);
+
+		self
+			. entries
+			. iter ()
+			. find (|(entry_key, _)| entry_key == key)
+			. map (|(_, value)| value . clone ())
+	}
+
+	fn put (&mut self, key: String, value: String) -> Result <(), String>
+	{
+		self . calls . set (self . calls . get () + 1);
+
+		if ! self . online
+		{
+			return Err ("offline" . to_string ());
+		}
+
+		self . entries . push ((key, value));
+		Ok (())
+	}
+
+	fn len (&self) -> usize
+	{
+		self . entries . len ()
+	}
+
+	fn redeem (&self, ticket: Ticket) -> Option <String>
+	{
+		self . get (&ticket . 0)
+	}
+
+	fn into_keys (self) -> Result <Vec <String>, String>
+	{
+		Ok (self . entries . into_iter () . map (|(key, _)| key) . collect ())
+	}
+}
+
+#[forward_receiver]
+struct Client
+{
+	primary: Backend,
+	secondary: Backend
+}
+
+// Methods returning `Result` or `Option` are retried on the secondary when the
+// primary fails.  Other methods, methods that consume the receiver, and methods
+// with arguments that aren't known to be cloneable are only forwarded to the
+// primary.
+forward_traits! (for Client . primary else secondary impl Store);
+
+#[forward_receiver]
+struct Chain (Backend, Backend, Backend);
+
+forward_traits! (for Chain . 0 else 1 else 2 impl Store);
+
+fn main ()
+{
+	let mut client = Client {primary: Backend::new (false), secondary: Backend::new (true)};
+
+	assert_eq! (client . put ("a" . to_string (), "1" . to_string ()), Ok (()));
+	assert_eq! (client . primary . calls . get (), 1);
+	assert_eq! (client . secondary . calls . get (), 1);
+
+	assert_eq! (client . get ("a"), Some ("1" . to_string ()));
+	assert_eq! (client . get ("b"), None);
+	assert_eq! (client . primary . calls . get (), 3);
+	assert_eq! (client . secondary . calls . get (), 3);
+
+	// The primary is empty, and neither `len` nor `redeem` are retried.
+	assert_eq! (client . len (), 0);
+	assert_eq! (client . redeem (Ticket ("a" . to_string ())), None);
+	assert_eq! (client . primary . calls . get (), 4);
+	assert_eq! (client . secondary . calls . get (), 3);
+	assert_eq! (client . into_keys (), Ok (Vec::new ()));
+
+	let mut chain = Chain (Backend::new (false), Backend::new (true), Backend::new (true));
+
+	assert_eq! (chain . put ("c" . to_string (), "3" . to_string ()), Ok (()));
+	assert_eq! (chain . 0 . calls . get (), 1);
+	assert_eq! (chain . 1 . calls . get (), 1);
+
+	// The call succeeded on the second backend, so the third isn't called.
+	assert_eq! (chain . 2 . calls . get (), 0);
+	assert_eq! (chain . get ("c"), Some ("3" . to_string ()));
+}
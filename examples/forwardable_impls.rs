@@ -0,0 +1,132 @@
+use forward_traits::{forward_receiver, forward_inherent};
+
+mod counters
+{
+	use forward_traits::forwardable;
+
+	pub struct Counter
+	{
+		count: u32
+	}
+
+	// Only the public methods are recorded.
+	#[forwardable]
+	impl Counter
+	{
+		pub fn new () -> Self
+		{
+			Self {count: 0}
+		}
+
+		/// Increments the count, returning the new count.
+		pub fn increment (&mut self, by: u32) -> u32
+		{
+			self . bump (by);
+			self . count
+		}
+
+		pub fn count (&self) -> u32
+		{
+			self . count
+		}
+
+		pub fn into_count (mut self) -> u32
+		{
+			self . bump (0);
+			self . count
+		}
+
+		fn bump (&mut self, by: u32)
+		{
+			self . count += by;
+		}
+	}
+
+	pub struct Label (pub String);
+
+	#[forwardable]
+	impl Label
+	{
+		pub fn text (&self) -> &str
+		{
+			&self . 0
+		}
+	}
+}
+
+// A type of the same name as one in `counters`, which can be made forwardable
+// all the same.
+mod tags
+{
+	use forward_traits::forwardable;
+
+	pub struct Label (pub &'static str);
+
+	#[forwardable]
+	impl Label
+	{
+		pub fn tag (&self) -> String
+		{
+			format! ("#{}", self . 0)
+		}
+	}
+}
+
+use counters::{Counter, Label};
+
+// The impl blocks are referred to by the paths of the modules that they're in,
+// since importing the types doesn't import their forwarding information.
+#[forward_receiver]
+struct Tally
+{
+	counter: Counter
+}
+
+// Methods are selected by name.
+forward_inherent! (for Tally . counter impl counters::Counter::{increment, count});
+
+#[forward_receiver]
+struct Badge
+{
+	counter: Counter,
+	label: Label
+}
+
+// `new` can't be forwarded, since the receiver can't be built from a counter
+// alone.
+forward_inherent!
+(
+	for Badge . counter impl counters::Counter::{increment, count, into_count}
+);
+
+// Without braces, every public method is forwarded.
+forward_inherent! (for Badge . label impl counters::Label);
+
+#[forward_receiver]
+struct Topic
+{
+	tag: tags::Label
+}
+
+forward_inherent! (for Topic . tag impl tags::Label);
+
+fn main ()
+{
+	let mut tally = Tally {counter: Counter::new ()};
+
+	assert_eq! (tally . increment (2), 2);
+	assert_eq! (tally . increment (3), 5);
+	assert_eq! (tally . count (), 5);
+
+	let mut badge = Badge {counter: Counter::new (), label: Label ("visits" . to_string ())};
+
+	badge . increment (1);
+
+	assert_eq! (badge . count (), 1);
+	assert_eq! (badge . text (), "visits");
+	assert_eq! (badge . into_count (), 1);
+
+	let topic = Topic {tag: tags::Label ("rust")};
+
+	assert_eq! (topic . tag (), "#rust");
+}
@@ -0,0 +1,94 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Collection
+{
+	fn extend_from (&mut self, items: impl IntoIterator <Item = Self>)
+	where Self: Sized;
+
+	fn absorb (&mut self, items: impl Iterator <Item = Self> + Clone)
+	where Self: Sized;
+
+	fn add (&mut self, other: impl Into <Self>)
+	where Self: Sized;
+
+	fn matches (&self, other: impl AsRef <Self>) -> bool;
+}
+
+#[derive (Clone, Debug, PartialEq)]
+struct Bag (Vec <u32>);
+
+impl From <u32> for Bag
+{
+	fn from (x: u32) -> Self
+	{
+		Self (vec! [x])
+	}
+}
+
+impl Collection for Bag
+{
+	fn extend_from (&mut self, items: impl IntoIterator <Item = Self>)
+	{
+		for item in items
+		{
+			self . 0 . extend (item . 0);
+		}
+	}
+
+	fn absorb (&mut self, items: impl Iterator <Item = Self> + Clone)
+	{
+		self . extend_from (items);
+	}
+
+	fn add (&mut self, other: impl Into <Self>)
+	{
+		self . 0 . extend (other . into () . 0);
+	}
+
+	fn matches (&self, other: impl AsRef <Self>) -> bool
+	{
+		*self == *other . as_ref ()
+	}
+}
+
+#[derive (Debug, PartialEq)]
+#[forward_receiver]
+struct Named
+{
+	name: &'static str,
+	bag: Bag
+}
+
+impl From <u32> for Named
+{
+	fn from (x: u32) -> Self
+	{
+		Self {name: "", bag: Bag::from (x)}
+	}
+}
+
+impl AsRef <Named> for Named
+{
+	fn as_ref (&self) -> &Self
+	{
+		self
+	}
+}
+
+forward_traits! (for Named . bag impl Collection);
+
+fn main ()
+{
+	let mut named = Named {name: "a", bag: Bag (vec! [1])};
+
+	named . extend_from (vec! [Named::from (2), Named::from (3)]);
+	named . absorb ((4..5) . map (Named::from));
+	named . add (5);
+
+	assert_eq! (named . name, "a");
+	assert_eq! (named . bag, Bag (vec! [1, 2, 3, 4, 5]));
+
+	let other = Named {name: "b", bag: Bag (vec! [1, 2, 3, 4, 5])};
+	assert! (named . matches (&other));
+}
@@ -0,0 +1,59 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Stack <T>
+{
+	fn push (&mut self, value: T);
+
+	fn pop (&mut self) -> Option <T>;
+
+	fn len (&self) -> usize;
+
+	fn is_empty (&self) -> bool;
+}
+
+#[forward_receiver]
+struct History <T> (Vec <T>);
+
+// `Vec` doesn't implement `Stack`, but has inherent methods for all of it.
+forward_traits! (for History . 0 impl Stack <T> via inherent);
+
+#[forwardable]
+trait Text
+{
+	fn size (&self) -> usize;
+
+	fn push_str (&mut self, text: &str);
+
+	fn as_str (&self) -> &str;
+}
+
+#[forward_receiver]
+struct Buffer
+{
+	text: String
+}
+
+// Methods of other names can still be renamed.
+forward_traits! (for Buffer . text impl Text via inherent {size => len});
+
+fn main ()
+{
+	let mut history = History (Vec::new ());
+
+	assert! (history . is_empty ());
+
+	history . push (1);
+	history . push (2);
+
+	assert_eq! (history . len (), 2);
+	assert_eq! (history . pop (), Some (2));
+	assert_eq! (history . 0, [1]);
+
+	let mut buffer = Buffer {text: String::new ()};
+
+	buffer . push_str ("hello");
+
+	assert_eq! (buffer . size (), 5);
+	assert_eq! (buffer . as_str (), "hello");
+}
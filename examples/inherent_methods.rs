@@ -0,0 +1,71 @@
+use forward_traits::{forward_receiver, forward_methods};
+
+mod names
+{
+	use forward_traits::{forward_receiver, forward_methods};
+
+	#[forward_receiver]
+	pub struct Names (pub Vec <String>);
+
+	// Visibility is kept, so these methods can be called from outside of this
+	// module.
+	forward_methods!
+	(
+		for Names . 0
+		{
+			/// The number of names.
+			pub fn len (&self) -> usize;
+
+			pub fn is_empty (&self) -> bool;
+
+			// Slice methods aren't found through `Vec`'s dereference, but the
+			// slice itself can be forwarded.
+			pub fn as_slice (&self) -> &[String];
+
+			pub fn push (&mut self, name: String);
+		}
+	);
+}
+
+#[forward_receiver]
+struct Queue <T>
+{
+	items: std::collections::VecDeque <T>
+}
+
+forward_methods!
+(
+	for Queue . items
+	{
+		fn push_back (&mut self, item: T);
+
+		fn pop_front (&mut self) -> Option <T>;
+
+		fn front (&self) -> Option <&T>;
+
+		fn len (&self) -> usize;
+	}
+);
+
+fn main ()
+{
+	let mut names = names::Names (Vec::new ());
+
+	assert! (names . is_empty ());
+
+	names . push ("Ada" . to_string ());
+	names . push ("Grace" . to_string ());
+
+	assert_eq! (names . len (), 2);
+	assert! (names . as_slice () . contains (&"Grace" . to_string ()));
+	assert_eq! (names . as_slice (), ["Ada", "Grace"]);
+
+	let mut queue = Queue {items: std::collections::VecDeque::new ()};
+
+	queue . push_back (1);
+	queue . push_back (2);
+
+	assert_eq! (queue . front (), Some (&1));
+	assert_eq! (queue . pop_front (), Some (1));
+	assert_eq! (queue . len (), 1);
+}
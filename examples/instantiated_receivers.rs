@@ -0,0 +1,89 @@
+use std::marker::PhantomData;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Describe
+{
+	fn describe (&self) -> String;
+}
+
+#[forwardable]
+trait Scale
+{
+	fn scale (&mut self, factor: u32);
+}
+
+impl Describe for f64
+{
+	fn describe (&self) -> String
+	{
+		format! ("{:.1}", self)
+	}
+}
+
+impl Describe for u32
+{
+	fn describe (&self) -> String
+	{
+		format! ("{}", self)
+	}
+}
+
+impl Scale for u32
+{
+	fn scale (&mut self, factor: u32)
+	{
+		*self *= factor;
+	}
+}
+
+struct Meters;
+struct Seconds;
+
+#[forward_receiver]
+struct Quantity <T, Unit = Meters>
+{
+	value: T,
+	unit: PhantomData <Unit>
+}
+
+// Only lengths in floating point can be described.
+forward_traits! (for Quantity <f64, Meters> . value impl Describe);
+
+// Any integral quantity can be scaled, whatever its unit.
+forward_traits! (for <U> Quantity <u32, U> . value impl Scale);
+
+// The unit's default is filled in when it isn't given.
+forward_traits! (for Quantity <u32> . value impl Describe);
+
+#[forward_receiver]
+struct Counter <T = u32> (T);
+
+// Receivers with defaults can still be forwarded in general.
+forward_traits! (for Counter . 0 impl Scale);
+
+fn main ()
+{
+	let length = Quantity::<f64, Meters> {value: 2.5, unit: PhantomData};
+
+	assert_eq! (length . describe (), "2.5");
+
+	let mut duration = Quantity::<u32, Seconds> {value: 3, unit: PhantomData};
+
+	duration . scale (2);
+
+	assert_eq! (duration . value, 6);
+
+	let mut count = Quantity::<u32> {value: 4, unit: PhantomData};
+
+	count . scale (3);
+
+	assert_eq! (count . describe (), "12");
+
+	let mut counter = Counter (5);
+
+	counter . scale (2);
+
+	assert_eq! (counter . 0, 10);
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Storage
+{
+	type Key;
+
+	fn get (&self, key: &Self::Key) -> Option <u32>;
+	fn contains (&self, key: &Self::Key) -> bool;
+	fn put (&mut self, key: Self::Key, value: u32);
+	fn delete (&mut self, key: &Self::Key);
+	fn name (&self) -> &'static str;
+}
+
+struct Cache (HashMap <String, u32>);
+
+impl Storage for Cache
+{
+	type Key = String;
+
+	fn get (&self, key: &String) -> Option <u32>
+	{
+		self . 0 . get (key) . copied ()
+	}
+
+	fn contains (&self, key: &String) -> bool
+	{
+		self . 0 . contains_key (key)
+	}
+
+	fn put (&mut self, key: String, value: u32)
+	{
+		self . 0 . insert (key, value);
+	}
+
+	fn delete (&mut self, key: &String)
+	{
+		self . 0 . remove (key);
+	}
+
+	fn name (&self) -> &'static str
+	{
+		"cache"
+	}
+}
+
+// Writes are logged, so that they can be replayed into the cache.
+struct Backend (Vec <(String, Option <u32>)>);
+
+impl Storage for Backend
+{
+	type Key = String;
+
+	fn get (&self, key: &String) -> Option <u32>
+	{
+		self . 0 . iter () . rev () . find (|(k, _)| k == key) . and_then (|(_, v)| *v)
+	}
+
+	fn contains (&self, key: &String) -> bool
+	{
+		self . get (key) . is_some ()
+	}
+
+	fn put (&mut self, key: String, value: u32)
+	{
+		self . 0 . push ((key, Some (value)));
+	}
+
+	fn delete (&mut self, key: &String)
+	{
+		self . 0 . push ((key . clone (), None));
+	}
+
+	fn name (&self) -> &'static str
+	{
+		"backend"
+	}
+}
+
+#[forward_receiver]
+struct Store
+{
+	cache: Cache,
+	backend: Backend
+}
+
+// Reads go to the cache, writes go to the backend, and everything else (`Key`
+// and `name`) goes to the member given first.
+forward_traits!
+(
+	for Store . backend
+	impl Storage
+	{
+		get, contains => . cache;
+		put, delete => . backend
+	}
+);
+
+fn main ()
+{
+	let mut store = Store
+	{
+		cache: Cache (HashMap::from ([("a" . to_owned (), 1)])),
+		backend: Backend (Vec::new ())
+	};
+
+	store . put ("b" . to_owned (), 2);
+	store . delete (&"a" . to_owned ());
+
+	assert_eq! (store . get (&"a" . to_owned ()), Some (1));
+	assert! (! store . contains (&"b" . to_owned ()));
+	assert_eq! (store . backend . get (&"b" . to_owned ()), Some (2));
+	assert_eq! (store . backend . get (&"a" . to_owned ()), None);
+	assert_eq! (store . name (), "backend");
+}
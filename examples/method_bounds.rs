@@ -0,0 +1,86 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Accumulate
+{
+	fn merge <T: Into <Self>> (&mut self, other: T)
+	where Self: Sized;
+
+	fn split <T> (&self) -> T
+	where T: From <Self>, Self: Clone;
+
+	fn describe (&self) -> String
+	where Self: std::fmt::Debug;
+}
+
+#[derive (Clone, Debug, PartialEq)]
+struct Total (u32);
+
+impl From <u32> for Total
+{
+	fn from (x: u32) -> Self
+	{
+		Self (x)
+	}
+}
+
+impl Accumulate for Total
+{
+	fn merge <T: Into <Self>> (&mut self, other: T)
+	where Self: Sized
+	{
+		self . 0 += other . into () . 0;
+	}
+
+	fn split <T> (&self) -> T
+	where T: From <Self>, Self: Clone
+	{
+		T::from (self . clone ())
+	}
+
+	fn describe (&self) -> String
+	where Self: std::fmt::Debug
+	{
+		format! ("{:?}", self)
+	}
+}
+
+// `merge` is called with `T = Total`, after converting the argument into
+// `Tagged` and then into `Total`.  `split` is called with `T = Total` as well,
+// and the result is converted back.  The `Self` bounds are checked against
+// `Total`.
+#[derive (Clone, Debug, PartialEq)]
+#[repr (transparent)]
+#[forward_receiver]
+struct Tagged (Total);
+
+impl From <u32> for Tagged
+{
+	fn from (x: u32) -> Self
+	{
+		Self (Total (x))
+	}
+}
+
+impl From <Tagged> for u64
+{
+	fn from (t: Tagged) -> Self
+	{
+		(t . 0 . 0 as u64) * 2
+	}
+}
+
+forward_traits! (for Tagged . 0 impl Accumulate);
+
+fn main ()
+{
+	let mut tagged = Tagged (Total (1));
+
+	tagged . merge (2u32);
+	assert_eq! (tagged, Tagged (Total (3)));
+
+	assert_eq! (tagged . split::<Tagged> (), Tagged (Total (3)));
+	assert_eq! (tagged . split::<u64> (), 6);
+
+	assert_eq! (tagged . describe (), "Total(3)");
+}
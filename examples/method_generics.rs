@@ -0,0 +1,45 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Layout
+{
+	fn size_of <T> () -> usize;
+	fn get <const N: usize> (&self) -> u8;
+	fn parse <'a, T> (&'a self, x: impl Into <u8>) -> Option <T>
+	where T: std::convert::TryFrom <u8>;
+}
+
+struct Bytes ([u8; 4]);
+
+impl Layout for Bytes
+{
+	fn size_of <T> () -> usize
+	{
+		std::mem::size_of::<T> ()
+	}
+
+	fn get <const N: usize> (&self) -> u8
+	{
+		self . 0 [N]
+	}
+
+	fn parse <'a, T> (&'a self, x: impl Into <u8>) -> Option <T>
+	where T: std::convert::TryFrom <u8>
+	{
+		T::try_from (x . into () + self . 0 [0]) . ok ()
+	}
+}
+
+#[forward_receiver]
+struct Wrapper (Bytes);
+
+forward_traits! (for Wrapper . 0 impl Layout);
+
+fn main ()
+{
+	let w = Wrapper (Bytes ([1, 2, 3, 4]));
+
+	assert_eq! (Wrapper::size_of::<u64> (), 8);
+	assert_eq! (w . get::<2> (), 3);
+	assert_eq! (w . parse::<i8> (2u8), Some (3i8));
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Len
+{
+	fn len (&self) -> usize;
+
+	fn is_empty (&self) -> bool;
+}
+
+// A trait of another crate, say, which the delegated types implement instead.
+trait Collection
+{
+	fn size (&self) -> usize;
+
+	fn is_bare (&self) -> bool
+	{
+		self . size () == 0
+	}
+}
+
+struct Shelf
+{
+	books: Vec <&'static str>
+}
+
+impl Collection for Shelf
+{
+	fn size (&self) -> usize
+	{
+		self . books . len ()
+	}
+}
+
+#[forward_receiver]
+struct Library
+{
+	shelf: Shelf
+}
+
+// Shelves don't implement `Len`, so each of its methods is forwarded to a
+// method of `Collection`.
+forward_traits!
+(
+	for Library . shelf
+	impl Len {len => Collection::size; is_empty => Collection::is_bare}
+);
+
+#[forward_receiver]
+struct Annex <T> (T);
+
+// The generic receiver requires `T: Collection`.
+forward_traits! (for Annex . 0 impl Len {len => Collection::size; is_empty => Collection::is_bare});
+
+#[forwardable]
+trait KeyValue
+{
+	fn lookup (&self, key: &str) -> Option <&u32>;
+
+	fn store (&mut self, key: String, value: u32) -> Option <u32>;
+
+	fn count (&self) -> usize;
+}
+
+#[forward_receiver]
+struct Registry
+{
+	entries: HashMap <String, u32>
+}
+
+// Methods named without a trait are inherent methods of the delegated type.
+forward_traits!
+(
+	for Registry . entries
+	impl KeyValue {lookup => get; store => insert; count => len}
+);
+
+fn main ()
+{
+	let library = Library {shelf: Shelf {books: vec! ["Dune", "Emma"]}};
+
+	assert_eq! (Len::len (&library), 2);
+	assert! (! Len::is_empty (&library));
+
+	let annex = Annex (Shelf {books: vec! []});
+
+	assert_eq! (Len::len (&annex), 0);
+	assert! (Len::is_empty (&annex));
+
+	let mut registry = Registry {entries: HashMap::new ()};
+
+	assert_eq! (registry . store ("a" . to_string (), 1), None);
+	assert_eq! (registry . store ("a" . to_string (), 2), Some (1));
+	assert_eq! (registry . lookup ("a"), Some (&2));
+	assert_eq! (registry . lookup ("b"), None);
+	assert_eq! (registry . count (), 1);
+}
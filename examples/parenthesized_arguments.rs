@@ -0,0 +1,75 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Handler <Args>
+{
+	type Output;
+
+	fn handle (&self, args: Args) -> Self::Output;
+}
+
+#[forwardable]
+trait Visitor <Args>
+{
+	fn visit (&mut self, args: Args);
+}
+
+struct Adder;
+
+impl Handler <(u32, u32)> for Adder
+{
+	type Output = u32;
+
+	fn handle (&self, (x, y): (u32, u32)) -> u32
+	{
+		x + y
+	}
+}
+
+impl Handler <(u32,)> for Adder
+{
+	type Output = u32;
+
+	fn handle (&self, (x,): (u32,)) -> u32
+	{
+		x + 1
+	}
+}
+
+#[derive (Default)]
+struct Collector
+{
+	seen: Vec <String>
+}
+
+impl <'a> Visitor <(&'a str,)> for Collector
+{
+	fn visit (&mut self, (name,): (&'a str,))
+	{
+		self . seen . push (name . to_owned ());
+	}
+}
+
+#[forward_receiver]
+struct Logged <T> (T);
+
+// `Handler (u32, u32) -> u32` is shorthand for `Handler <(u32, u32)>` where the
+// delegated implementation has `Output = u32`.
+forward_traits! (for Logged . 0 impl Handler (u32, u32) -> u32 + Handler (u32) -> u32);
+
+forward_traits! (for Logged . 0 impl for <'a> Visitor (&'a str));
+
+fn main ()
+{
+	let adder = Logged (Adder);
+
+	assert_eq! (adder . handle ((1, 2)), 3);
+	assert_eq! (adder . handle ((1,)), 2);
+
+	let mut collector = Logged (Collector::default ());
+
+	collector . visit (("a",));
+	collector . visit (("b",));
+
+	assert_eq! (collector . 0 . seen, vec! ["a", "b"]);
+}
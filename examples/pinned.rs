@@ -0,0 +1,134 @@
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use forward_traits
+::{
+	forwardable,
+	supply_forwarding_info_for_trait,
+	forward_receiver,
+	forward_traits
+};
+
+supply_forwarding_info_for_trait!
+(
+	std::future::Future,
+	trait
+	{
+		type Output;
+		fn poll (self: std::pin::Pin <&mut Self>, cx: &mut std::task::Context <'_>)
+		-> std::task::Poll <Self::Output>;
+	}
+);
+
+#[forwardable]
+trait Countdown
+{
+	fn remaining (self: Pin <&Self>) -> u32;
+}
+
+// Not Unpin, so it can only be polled through a structurally pinned member.
+struct Timer
+{
+	ticks: u32,
+	_pinned: PhantomPinned
+}
+
+impl std::future::Future for Timer
+{
+	type Output = &'static str;
+
+	fn poll (self: Pin <&mut Self>, cx: &mut Context <'_>) -> Poll <Self::Output>
+	{
+		let this = unsafe { self . get_unchecked_mut () };
+
+		if this . ticks == 0 { return Poll::Ready ("done"); }
+
+		this . ticks -= 1;
+		cx . waker () . wake_by_ref ();
+		Poll::Pending
+	}
+}
+
+impl Countdown for Timer
+{
+	fn remaining (self: Pin <&Self>) -> u32
+	{
+		self . ticks
+	}
+}
+
+#[forward_receiver]
+struct Labelled
+{
+	label: &'static str,
+	#[pin]
+	timer: Timer
+}
+
+forward_traits! (for Labelled . timer impl Future + Countdown);
+
+// Unpin members don't need to be marked, and are projected with `Pin::new`.
+struct Ready (u32);
+
+impl std::future::Future for Ready
+{
+	type Output = u32;
+
+	fn poll (self: Pin <&mut Self>, _cx: &mut Context <'_>) -> Poll <u32>
+	{
+		Poll::Ready (self . 0)
+	}
+}
+
+#[forward_receiver]
+struct Boxed (Ready);
+
+forward_traits! (for Boxed . 0 impl Future);
+
+// Conversion delegation works the same way, as long as both types are Unpin.
+#[forward_receiver]
+struct Converted (Ready);
+
+impl AsRef <Ready> for Converted
+{
+	fn as_ref (&self) -> &Ready
+	{
+		&self . 0
+	}
+}
+
+impl AsMut <Ready> for Converted
+{
+	fn as_mut (&mut self) -> &mut Ready
+	{
+		&mut self . 0
+	}
+}
+
+forward_traits! (for Converted -> Ready impl Future);
+
+fn main ()
+{
+	let mut cx = Context::from_waker (Waker::noop ());
+
+	let labelled = Labelled
+	{
+		label: "timer",
+		timer: Timer {ticks: 2, _pinned: PhantomPinned}
+	};
+	let mut labelled = std::pin::pin! (labelled);
+
+	assert_eq! (labelled . label, "timer");
+	assert_eq! (labelled . as_ref () . remaining (), 2);
+	assert_eq! (labelled . as_mut () . poll (&mut cx), Poll::Pending);
+	assert_eq! (labelled . as_mut () . poll (&mut cx), Poll::Pending);
+	assert_eq! (labelled . as_ref () . remaining (), 0);
+	assert_eq! (labelled . as_mut () . poll (&mut cx), Poll::Ready ("done"));
+
+	let mut boxed = Boxed (Ready (7));
+	assert_eq! (Pin::new (&mut boxed) . poll (&mut cx), Poll::Ready (7));
+
+	let mut converted = Converted (Ready (3));
+	assert_eq! (Pin::new (&mut converted) . poll (&mut cx), Poll::Ready (3));
+}
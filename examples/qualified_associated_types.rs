@@ -0,0 +1,108 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Labelled
+{
+	type Label;
+
+	fn label (&self) -> <Self as Labelled>::Label;
+}
+
+#[forwardable]
+trait Store: Labelled
+{
+	type Item;
+
+	fn get (&self, index: usize) -> Option <<Self as Store>::Item>;
+	fn put (&mut self, item: <Self as Store>::Item);
+	fn ends (&self) -> (<Self as Store>::Item, <Self as Store>::Item);
+	fn tag (&self) -> <Self as Labelled>::Label;
+}
+
+struct Shelf
+{
+	items: Vec <u32>
+}
+
+impl Labelled for Shelf
+{
+	type Label = &'static str;
+
+	fn label (&self) -> &'static str
+	{
+		"shelf"
+	}
+}
+
+impl Store for Shelf
+{
+	type Item = u32;
+
+	fn get (&self, index: usize) -> Option <u32>
+	{
+		self . items . get (index) . copied ()
+	}
+
+	fn put (&mut self, item: u32)
+	{
+		self . items . push (item);
+	}
+
+	fn ends (&self) -> (u32, u32)
+	{
+		(self . items [0], self . items [self . items . len () - 1])
+	}
+
+	fn tag (&self) -> &'static str
+	{
+		"stored"
+	}
+}
+
+#[derive (Debug, PartialEq)]
+struct Crate (u32);
+
+impl From <u32> for Crate
+{
+	fn from (x: u32) -> Self
+	{
+		Self (x)
+	}
+}
+
+impl From <Crate> for u32
+{
+	fn from (x: Crate) -> Self
+	{
+		x . 0
+	}
+}
+
+#[forward_receiver]
+struct Warehouse <T>
+{
+	shelf: T
+}
+
+forward_traits! (for Warehouse . shelf impl Labelled);
+
+// `<Self as Store>::Item` is transformed the same way as `Self::Item`, and
+// `<Self as Labelled>::Label` refers to the shelf's label through the
+// `Labelled` implementation above.
+forward_traits!
+(
+	for Warehouse . shelf [Self::Item -> Crate]
+	impl Store
+);
+
+fn main ()
+{
+	let mut warehouse = Warehouse {shelf: Shelf {items: vec! [1, 2]}};
+
+	warehouse . put (Crate (3));
+
+	assert_eq! (warehouse . get (2), Some (Crate (3)));
+	assert_eq! (warehouse . ends (), (Crate (1), Crate (3)));
+	assert_eq! (warehouse . label (), "shelf");
+	assert_eq! (warehouse . tag (), "stored");
+}
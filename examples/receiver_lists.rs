@@ -0,0 +1,68 @@
+use forward_traits
+::{
+	supply_forwarding_info_for_trait,
+	forward_receiver,
+	forward_traits
+};
+
+supply_forwarding_info_for_trait!
+(
+	std::fmt::Display,
+	trait
+	{
+		fn fmt (&self, f: &mut std::fmt::Formatter <'_>) -> std::fmt::Result;
+	}
+);
+
+supply_forwarding_info_for_trait!
+(
+	std::ops::Add,
+	trait <Rhs = Self>
+	{
+		type Output;
+		fn add (self, rhs: Rhs) -> Self::Output;
+	}
+);
+
+#[forward_receiver]
+struct Meters (f64);
+
+#[forward_receiver]
+struct Seconds (f64);
+
+#[forward_receiver]
+struct Grams (f64);
+
+#[forward_receiver]
+struct Labelled <T>
+{
+	label: &'static str,
+	value: T
+}
+
+#[forward_receiver]
+struct Checked <T> (T);
+
+// Every receiver in the braces gets every listed trait.
+forward_traits! (for {Meters . 0, Seconds . 0, Grams . 0} impl Display);
+
+// Generics and where clauses are shared by the receivers in the list.
+forward_traits!
+(
+	for <T> {Labelled <T> . value, Checked <T> . 0}
+	where T: Copy
+	impl Add <f64>
+);
+
+fn main ()
+{
+	assert_eq! (Meters (1.5) . to_string (), "1.5");
+	assert_eq! (Seconds (2.0) . to_string (), "2");
+	assert_eq! (Grams (0.25) . to_string (), "0.25");
+
+	let labelled = Labelled {label: "weight", value: 1.0};
+
+	assert_eq! (labelled . label, "weight");
+	assert_eq! (labelled + 2.0, 3.0);
+	assert_eq! (Checked (1.0) + 2.0, 3.0);
+}
@@ -0,0 +1,65 @@
+use forward_traits::{forwardable, forward_traits};
+
+#[forwardable]
+trait Balance
+{
+	fn balance (&self) -> i64;
+	fn deposit (&mut self, amount: i64);
+}
+
+impl Balance for Vec <i64>
+{
+	fn balance (&self) -> i64
+	{
+		self . iter () . sum ()
+	}
+
+	fn deposit (&mut self, amount: i64)
+	{
+		self . push (amount);
+	}
+}
+
+mod model
+{
+	use forward_traits::forward_receiver;
+
+	#[forward_receiver]
+	pub struct Account
+	{
+		pub owner: String,
+		pub inner: std::vec::Vec <i64>
+	}
+
+	pub mod nested
+	{
+		use forward_traits::forward_receiver;
+
+		#[forward_receiver]
+		pub struct Savings <T> (pub T);
+	}
+}
+
+// Neither receiver is in scope here, so they're named by their paths.
+forward_traits! (for crate::model::Account . inner impl Balance);
+forward_traits! (for model::nested::Savings . 0 impl Balance);
+
+fn main ()
+{
+	let mut account = model::Account
+	{
+		owner: "someone" . to_owned (),
+		inner: vec! [10]
+	};
+
+	account . deposit (5);
+
+	assert_eq! (account . balance (), 15);
+	assert_eq! (account . owner, "someone");
+
+	let mut savings = model::nested::Savings (account);
+
+	savings . deposit (-3);
+
+	assert_eq! (savings . balance (), 12);
+}
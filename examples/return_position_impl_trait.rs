@@ -0,0 +1,43 @@
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Values
+{
+	fn values (&self) -> impl Iterator <Item = u32> + '_;
+	fn doubled (&self) -> impl Iterator <Item = u32>;
+}
+
+struct Numbers (Vec <u32>);
+
+impl Values for Numbers
+{
+	fn values (&self) -> impl Iterator <Item = u32> + '_
+	{
+		self . 0 . iter () . copied ()
+	}
+
+	fn doubled (&self) -> impl Iterator <Item = u32>
+	{
+		self . 0 . clone () . into_iter () . map (|x| x * 2)
+	}
+}
+
+#[forward_receiver]
+struct Labelled
+{
+	label: String,
+	numbers: Numbers
+}
+
+// The forwarded methods return whatever the delegated methods return, so they
+// capture exactly what the trait definition says they capture, in any edition.
+forward_traits! (for Labelled . numbers impl Values);
+
+fn main ()
+{
+	let labelled = Labelled {label: "numbers" . to_owned (), numbers: Numbers (vec! [1, 2])};
+
+	assert_eq! (labelled . values () . sum::<u32> (), 3);
+	assert_eq! (labelled . doubled () . collect::<Vec <_>> (), vec! [2, 4]);
+	assert_eq! (labelled . label, "numbers");
+}
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Node
+{
+	fn name (self: Rc <Self>) -> String;
+	fn spawn (self: Arc <Self>) -> usize;
+}
+
+#[derive (Clone)]
+struct Leaf (String);
+
+impl Node for Leaf
+{
+	fn name (self: Rc <Self>) -> String
+	{
+		self . 0 . clone ()
+	}
+
+	fn spawn (self: Arc <Self>) -> usize
+	{
+		self . 0 . len ()
+	}
+}
+
+// Conversion takes the value out of the pointer, cloning it if it's still
+// shared, and converts it into a new allocation.
+#[derive (Clone)]
+#[forward_receiver]
+struct Converted (Leaf);
+
+impl From <Converted> for Leaf
+{
+	fn from (c: Converted) -> Self
+	{
+		c . 0
+	}
+}
+
+forward_traits! (for Converted -> Leaf impl Node);
+
+// Member delegation moves the member out, or clones it if the receiver is still
+// shared.
+#[forward_receiver]
+struct Labelled
+{
+	label: &'static str,
+	leaf: Leaf
+}
+
+forward_traits! (for Labelled . leaf impl Node);
+
+#[forwardable]
+trait Graph
+{
+	fn share (self: Rc <Self>) -> Rc <Self>;
+}
+
+impl Graph for u32
+{
+	fn share (self: Rc <Self>) -> Rc <Self>
+	{
+		self
+	}
+}
+
+// Transparent receivers reuse the allocation, so sharing is preserved.
+#[repr (transparent)]
+#[forward_receiver]
+struct Id <T>
+{
+	id: u32,
+	_kind: PhantomData <T>
+}
+
+forward_traits! (for Id . id impl Graph);
+
+fn main ()
+{
+	let converted = Rc::new (Converted (Leaf (String::from ("leaf"))));
+	let other = converted . clone ();
+	assert_eq! (converted . name (), "leaf");
+	assert_eq! (Arc::new (Converted (Leaf (String::from ("leaf")))) . spawn (), 4);
+	assert_eq! (other . name (), "leaf");
+
+	let labelled = Labelled {label: "tree", leaf: Leaf (String::from ("oak"))};
+	assert_eq! (labelled . label, "tree");
+	assert_eq! (Rc::new (labelled) . name (), "oak");
+
+	let id = Rc::new (Id::<()> {id: 3, _kind: PhantomData});
+	let shared = id . clone () . share ();
+	assert! (Rc::ptr_eq (&id, &shared));
+	assert_eq! (shared . id, 3);
+}
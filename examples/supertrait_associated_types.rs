@@ -0,0 +1,50 @@
+use forward_traits
+::{
+	supply_forwarding_info_for_trait,
+	forward_receiver,
+	forward_traits
+};
+
+supply_forwarding_info_for_trait!
+(
+	std::ops::Index,
+	trait <Idx>
+	{
+		type Output;
+		fn index (&self, index: Idx) -> &Self::Output;
+	}
+);
+
+supply_forwarding_info_for_trait!
+(
+	std::ops::IndexMut,
+	trait <Idx>
+	where Self: Index <Idx>
+	{
+		fn index_mut (&mut self, index: Idx) -> &mut Self::Output;
+	}
+);
+
+#[forward_receiver]
+struct Column <C> (C);
+
+// `IndexMut` names `Self::Output` from its `Index` supertrait.  Since `Index`
+// is forwarded to the same member, `Column <C>`'s `Output` is tied to `C`'s in
+// the generated impl, so it holds for any `C`.
+forward_traits! (for Column . 0 impl for <Idx> Index <Idx> + for <Idx> IndexMut <Idx>);
+
+fn main ()
+{
+	let mut column = Column (vec! [1, 2, 3]);
+
+	column [1] = 5;
+
+	assert_eq! (column [1], 5);
+	assert_eq! (column . 0, vec! [1, 5, 3]);
+
+	let mut grid = Column (vec! [vec! ['a', 'b'], vec! ['c', 'd']]);
+
+	grid [1] [0] = 'x';
+
+	assert_eq! (grid [1], vec! ['x', 'd']);
+}
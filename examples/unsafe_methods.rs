@@ -0,0 +1,52 @@
+#![deny (unsafe_op_in_unsafe_fn)]
+
+use std::pin::Pin;
+
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait RawBuffer
+{
+	unsafe fn get_unchecked (&self, index: usize) -> u8;
+	unsafe fn set_unchecked (self: Pin <&mut Self>, index: usize, value: u8);
+}
+
+struct Bytes (Vec <u8>);
+
+impl RawBuffer for Bytes
+{
+	unsafe fn get_unchecked (&self, index: usize) -> u8
+	{
+		unsafe { *self . 0 . get_unchecked (index) }
+	}
+
+	unsafe fn set_unchecked (self: Pin <&mut Self>, index: usize, value: u8)
+	{
+		unsafe { *self . get_mut () . 0 . get_unchecked_mut (index) = value; }
+	}
+}
+
+#[forward_receiver]
+struct Named
+{
+	name: &'static str,
+	bytes: Bytes
+}
+
+// The delegated calls are wrapped in `unsafe` blocks, so this compiles even
+// where unsafe operations in unsafe functions need them.
+forward_traits! (for Named . bytes impl RawBuffer);
+
+fn main ()
+{
+	let mut named = Named {name: "bytes", bytes: Bytes (vec! [1, 2, 3])};
+
+	unsafe
+	{
+		Pin::new (&mut named) . set_unchecked (1, 5);
+
+		assert_eq! (named . get_unchecked (1), 5);
+	}
+
+	assert_eq! (named . name, "bytes");
+}
@@ -0,0 +1,569 @@
+use syn
+::{
+	Ident,
+	Type,
+	Expr,
+	Path,
+	Signature,
+	FnArg,
+	Receiver,
+	PatType,
+	ReturnType,
+	PathArguments,
+	TypeReference,
+	TypeParen,
+	TypeGroup,
+	TypeArray,
+	TypeTuple,
+	TraitItem,
+	TraitItemFn,
+	ImplItem,
+	ImplItemFn,
+	WherePredicate,
+	WhereClause,
+	parse_quote
+};
+use syn::parse::{Result, Error};
+use syn::fold::Fold;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::syn::member::Member;
+use crate::syn::reducers::Reducers;
+use crate::syn::inherent_methods::InherentMethod;
+use crate::syn::transformable_types::{std_last, std_type_arguments};
+
+use crate::value_transformer::element_transformer::ElementTransformer;
+
+use crate::transformer::{Transformer, rename_args, mentions_any};
+
+fn is_self (ty: &Type) -> bool
+{
+	matches! (ty, Type::Path (type_path) if type_path . path . is_ident ("Self"))
+}
+
+fn is_unit (output: &ReturnType) -> bool
+{
+	match output
+	{
+		ReturnType::Default => true,
+		ReturnType::Type (_, output_type) =>
+			matches! (output_type . as_ref (), Type::Tuple (tuple) if tuple . elems . is_empty ())
+	}
+}
+
+// The reducers that don't short-circuit, so that every delegate is called.
+// Any other path names a function that's passed an iterator of the results.
+fn reduce (reducer: &Path, results: TokenStream) -> Expr
+{
+	let builtin = reducer . get_ident () . map (Ident::to_string);
+
+	match builtin . as_deref ()
+	{
+		Some ("sum") => parse_quote! (std::iter::Iterator::sum (#results)),
+		Some ("product") => parse_quote! (std::iter::Iterator::product (#results)),
+		Some ("all") => parse_quote!
+		(
+			std::iter::Iterator::fold (#results, true, |all, result| all & result)
+		),
+		Some ("any") => parse_quote!
+		(
+			std::iter::Iterator::fold (#results, false, |any, result| any | result)
+		),
+		Some ("collect") => parse_quote! (std::iter::Iterator::collect (#results)),
+		_ => parse_quote! (#reducer (#results))
+	}
+}
+
+// Each call is only made if the ones before it failed, and the last call's
+// result is returned as is.
+fn chain_failures (calls: Vec <Expr>, failure_pattern: &TokenStream) -> Expr
+{
+	let result_ident = Ident::new ("result", Span::mixed_site ());
+
+	// There's always at least one delegate.
+	calls
+		. into_iter ()
+		. rev ()
+		. reduce
+		(
+			|fallback, call| parse_quote!
+			(
+				match #call
+				{
+					#failure_pattern => #fallback,
+					#result_ident => #result_ident
+				}
+			)
+		)
+		. unwrap ()
+}
+
+// The value that a call fails with, for methods returning `Result` or
+// `Option`.  Other types of the same names, including aliases like
+// `io::Result <T>`, aren't recognised, as their variants may differ.
+fn get_failure_pattern (output: &ReturnType) -> Option <TokenStream>
+{
+	let ReturnType::Type (_, output_type) = output else { return None; };
+
+	let is_prelude_type = |module, ident, arity| std_last (output_type, &[module])
+		. is_some_and
+		(
+			|segment| segment . ident == ident && matches!
+			(
+				&segment . arguments,
+				PathArguments::AngleBracketed (arguments) if arguments . args . len () == arity
+			)
+		);
+
+	if is_prelude_type ("result", "Result", 2)
+	{
+		Some (quote! (std::result::Result::Err (_)))
+	}
+	else if is_prelude_type ("option", "Option", 1)
+	{
+		Some (quote! (std::option::Option::None))
+	}
+	else
+	{
+		None
+	}
+}
+
+// The primitive types that are cloneable, by their bare names.
+const CLONE_PRIMITIVES: [&str; 17] =
+[
+	"bool", "char", "f32", "f64",
+	"i8", "i16", "i32", "i64", "i128", "isize",
+	"u8", "u16", "u32", "u64", "u128", "usize",
+	"str"
+];
+
+// Whether a type is known to be cloneable from its name alone.  Other types,
+// including ones that do implement `Clone`, can't be told apart from ones that
+// don't.
+fn is_known_clone (ty: &Type) -> bool
+{
+	match ty
+	{
+		Type::Reference (TypeReference {mutability: None, ..})
+			| Type::Ptr (_)
+			| Type::BareFn (_) => true,
+		Type::Paren (TypeParen {elem, ..})
+			| Type::Group (TypeGroup {elem, ..})
+			| Type::Array (TypeArray {elem, ..}) => is_known_clone (elem),
+		Type::Tuple (TypeTuple {elems, ..}) => elems . iter () . all (is_known_clone),
+		_ =>
+		{
+			let is_plain_std_type = |modules: &[&str], idents: &[&str]|
+				std_last (ty, modules) . is_some_and
+				(
+					|segment| segment . arguments . is_none ()
+						&& idents . iter () . any (|ident| segment . ident == ident)
+				);
+
+			let has_known_clone_arguments = |module, ident|
+				std_type_arguments (ty, &[module], ident)
+					. is_some_and (|arguments| arguments . into_iter () . all (is_known_clone));
+
+			is_plain_std_type (&[], &CLONE_PRIMITIVES)
+				|| is_plain_std_type (&["string"], &["String"])
+				|| std_type_arguments (ty, &["rc"], "Rc") . is_some ()
+				|| std_type_arguments (ty, &["sync"], "Arc") . is_some ()
+				|| has_known_clone_arguments ("option", "Option")
+				|| has_known_clone_arguments ("boxed", "Box")
+				|| has_known_clone_arguments ("vec", "Vec")
+		}
+	}
+}
+
+// A call can only be retried if the receiver and any other arguments of the
+// receiver type are borrowed, and other arguments taken by value are known to be
+// cloneable.
+fn is_retriable (sig: &Signature) -> bool
+{
+	let self_ident = [Ident::new ("Self", Span::call_site ())];
+
+	sig . inputs . iter () . all
+	(
+		|input| match input
+		{
+			FnArg::Receiver (Receiver {ty, ..}) => matches! (ty . as_ref (), Type::Reference (_)),
+			FnArg::Typed (PatType {ty, ..}) => matches! (ty . as_ref (), Type::Reference (_))
+				|| (
+					! mentions_any (ty . to_token_stream (), &self_ident)
+						&& is_known_clone (ty)
+				)
+		}
+	)
+}
+
+// How the methods of the forwarded trait are called on the delegates.
+pub enum Composition
+{
+	// Methods are called on every delegate, and their results are reduced.
+	Broadcast (Reducers),
+
+	// Methods are called on each of the elements of a member of the receiver,
+	// which is the single delegate, and their results are reduced.
+	Elements (Member, Reducers),
+
+	// Methods are called on each delegate in turn, until one of them succeeds.
+	Failover
+}
+
+impl Composition
+{
+	pub fn fold_types (self, folder: &mut impl Fold) -> Self
+	{
+		match self
+		{
+			Self::Broadcast (reducers) => Self::Broadcast (reducers . fold_types (folder)),
+			Self::Elements (collection, reducers) =>
+				Self::Elements (collection, reducers . fold_types (folder)),
+			Self::Failover => Self::Failover
+		}
+	}
+}
+
+// Forwards a trait to one or more delegates.  Associated types and constants
+// are taken from the first.
+pub struct CompositeTransformer
+{
+	transformers: Vec <Transformer>,
+	composition: Composition,
+
+	// Methods of the forwarded trait that are forwarded to methods of other
+	// names.
+	renamed_methods: Vec <(Ident, Path)>,
+
+	// Whether any items are forwarded to the delegates' implementations of the
+	// forwarded trait, which they then have to implement.
+	uses_forwarded_trait: bool
+}
+
+impl CompositeTransformer
+{
+	pub fn new (transformers: Vec <Transformer>, composition: Composition) -> Self
+	{
+		Self
+		{
+			transformers,
+			composition,
+			renamed_methods: Vec::new (),
+			uses_forwarded_trait: false
+		}
+	}
+
+	pub fn set_impl_params (&mut self, impl_params: &[Ident])
+	{
+		for transformer in &mut self . transformers
+		{
+			transformer . set_impl_params (impl_params . to_vec ());
+		}
+	}
+
+	fn take_method_predicates (&mut self) -> Vec <WherePredicate>
+	{
+		self
+			. transformers
+			. iter_mut ()
+			. flat_map (Transformer::take_method_predicates)
+			. collect ()
+	}
+
+	pub fn rename_method (&mut self, ident: Ident, method: Path)
+	{
+		self . renamed_methods . push ((ident, method));
+	}
+
+	fn get_renamed_method (&self, ident: &Ident) -> Option <Path>
+	{
+		self
+			. renamed_methods
+			. iter ()
+			. find (|(renamed_ident, _)| renamed_ident == ident)
+			. map (|(_, method)| method . clone ())
+	}
+
+	pub fn uses_forwarded_trait (&self) -> bool
+	{
+		self . uses_forwarded_trait
+	}
+
+	fn is_composite (&self) -> bool
+	{
+		self . transformers . len () > 1
+			|| matches! (self . composition, Composition::Elements (..))
+	}
+
+	pub fn get_transformers (&self) -> &[Transformer]
+	{
+		&self . transformers
+	}
+
+	pub fn get_transformed_forwarded_trait (&self) -> Option <&Path>
+	{
+		self . transformers [0] . get_transformed_forwarded_trait ()
+	}
+
+	pub fn get_delegated_type (&self) -> &Type
+	{
+		self . transformers [0] . get_delegated_type ()
+	}
+
+	pub fn add_supertrait_predicates
+	(
+		&mut self,
+		where_clause: Option <&WhereClause>,
+		items: &[TraitItem]
+	)
+	{
+		self . transformers [0] . add_supertrait_predicates (where_clause, items);
+	}
+
+	// The collection is iterated over in the same way that the receiver is
+	// taken.
+	fn get_elements (collection: &Member, sig: &Signature) -> Result <TokenStream>
+	{
+		let receiver_type = match sig . inputs . first ()
+		{
+			Some (FnArg::Receiver (Receiver {ty, ..})) => ty . as_ref (),
+			_ => return Err
+			(
+				Error::new_spanned
+				(
+					&sig . ident,
+					"Element delegation can only forward methods with a receiver"
+				)
+			)
+		};
+
+		let collection = match receiver_type
+		{
+			Type::Reference (TypeReference {mutability: Some (_), elem, ..})
+			if is_self (elem) =>
+				quote! (&mut self . #collection),
+			Type::Reference (TypeReference {mutability: None, elem, ..})
+			if is_self (elem) =>
+				quote! (&self . #collection),
+			ty if is_self (ty) => quote! (self . #collection),
+			_ => return Err
+			(
+				Error::new_spanned
+				(
+					receiver_type,
+					"Element delegation can only transform receivers taken by value or by reference"
+				)
+			)
+		};
+
+		Ok (quote! (std::iter::IntoIterator::into_iter (#collection)))
+	}
+
+	fn get_reducer (reducers: &Reducers, sig: &Signature) -> Result <Option <Path>>
+	{
+		let reducer = reducers . get (&sig . ident, &sig . output);
+
+		if reducer . is_none () && ! is_unit (&sig . output)
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					&sig . output,
+					"Methods that return values need a reducer to be forwarded to several delegates"
+				)
+			);
+		}
+
+		Ok (reducer . cloned ())
+	}
+
+	fn get_first_ok_pattern (reducer: &Path, sig: &Signature) -> Result <TokenStream>
+	{
+		get_failure_pattern (&sig . output) . ok_or_else
+		(
+			|| Error::new_spanned
+			(
+				reducer,
+				"`first_ok` can only reduce the results of methods returning `Result` or `Option`"
+			)
+		)
+	}
+
+	fn transform_calls (&mut self, sig: &Signature, method: Option <&Path>)
+	-> Result <Vec <Expr>>
+	{
+		let mut calls = Vec::new ();
+		for transformer in &mut self . transformers
+		{
+			calls . push (transformer . transform_call (sig, true, method)?);
+		}
+
+		Ok (calls)
+	}
+
+	// Each delegate is called only if the ones before it failed.  Methods that
+	// can't be retried are only forwarded to the first delegate.
+	fn transform_failover_call (&mut self, sig: &Signature, method: Option <&Path>)
+	-> Result <Expr>
+	{
+		let failure_pattern = get_failure_pattern (&sig . output)
+			. filter (|_| is_retriable (sig));
+
+		let Some (failure_pattern) = failure_pattern
+		else
+		{
+			return self . transformers [0] . transform_call (sig, false, method);
+		};
+
+		let calls = self . transform_calls (sig, method)?;
+
+		Ok (chain_failures (calls, &failure_pattern))
+	}
+
+	fn transform_item_fn (&mut self, item_fn: TraitItemFn) -> Result <ImplItemFn>
+	{
+		let sig = Signature {inputs: rename_args (item_fn . sig . inputs), .. item_fn . sig};
+
+		let method = self . get_renamed_method (&sig . ident);
+		let method = method . as_ref ();
+
+		if ! self . is_composite ()
+		{
+			let body_expr = self . transformers [0] . transform_call (&sig, false, method)?;
+			let predicates = self . take_method_predicates ();
+
+			return Ok (Transformer::build_item_fn (sig, predicates, body_expr));
+		}
+
+		if let Some (asyncness) = &sig . asyncness
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					asyncness,
+					"Async methods can't be forwarded to several delegates"
+				)
+			);
+		}
+
+		let body_expr = match &self . composition
+		{
+			Composition::Broadcast (reducers) =>
+			{
+				let reducer = Self::get_reducer (reducers, &sig)?;
+				let calls = self . transform_calls (&sig, method)?;
+
+				match reducer
+				{
+					Some (reducer) if reducer . is_ident ("first_ok") =>
+						chain_failures (calls, &Self::get_first_ok_pattern (&reducer, &sig)?),
+					Some (reducer) => reduce
+					(
+						&reducer,
+						quote! (std::iter::IntoIterator::into_iter ([#(#calls),*]))
+					),
+					None => parse_quote! ({ #(#calls;)* })
+				}
+			},
+			Composition::Elements (collection, reducers) =>
+			{
+				let reducer = Self::get_reducer (reducers, &sig)?;
+
+				if let Some (reducer) = reducer
+					. as_ref ()
+					. filter (|reducer| reducer . is_ident ("first_ok"))
+				{
+					return Err
+					(
+						Error::new_spanned
+						(
+							reducer,
+							"`first_ok` can't reduce the results of element delegation, as there may be no elements to take a result from"
+						)
+					);
+				}
+
+				let elements = Self::get_elements (collection, &sig)?;
+				let element_ident = ElementTransformer::get_element_ident ();
+				let calls = self . transform_calls (&sig, method)?;
+
+				match reducer
+				{
+					Some (reducer) => reduce
+					(
+						&reducer,
+						quote!
+						(
+							std::iter::Iterator::map
+							(
+								#elements,
+								|#element_ident| #(#calls)*
+							)
+						)
+					),
+					None => parse_quote!
+					({
+						for #element_ident in #elements
+						{
+							#(#calls;)*
+						}
+					})
+				}
+			},
+			Composition::Failover => self . transform_failover_call (&sig, method)?
+		};
+
+		let predicates = self . take_method_predicates ();
+
+		Ok (Transformer::build_item_fn (sig, predicates, body_expr))
+	}
+
+	pub fn transform_trait_item (&mut self, item: TraitItem) -> Result <ImplItem>
+	{
+		match item
+		{
+			TraitItem::Fn (item_fn) =>
+			{
+				if self . get_renamed_method (&item_fn . sig . ident) . is_none ()
+				{
+					self . uses_forwarded_trait = true;
+				}
+
+				Ok (ImplItem::Fn (self . transform_item_fn (item_fn)?))
+			},
+			item =>
+			{
+				self . uses_forwarded_trait = true;
+
+				self . transformers [0] . transform_trait_item (item)
+			}
+		}
+	}
+
+	// Forwards an inherent method to the delegates' inherent methods of the same
+	// name, keeping its attributes and visibility.
+	pub fn transform_inherent_method (&mut self, method: InherentMethod)
+	-> Result <ImplItemFn>
+	{
+		let InherentMethod {attrs, vis, sig, semi_token} = method;
+
+		let ident = sig . ident . clone ();
+		self . rename_method (ident . clone (), ident . into ());
+
+		let item_fn = TraitItemFn
+		{
+			attrs: Vec::new (),
+			sig,
+			default: None,
+			semi_token: Some (semi_token)
+		};
+
+		let item_fn = self . transform_item_fn (item_fn)?;
+
+		Ok (ImplItemFn {attrs, vis, .. item_fn})
+	}
+}
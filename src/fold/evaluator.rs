@@ -1,7 +1,19 @@
 use std::iter::repeat;
 
 use proc_macro2::TokenStream;
-use syn::{Path, PathArguments, Generics, GenericParam};
+use syn
+::{
+	Path,
+	PathArguments,
+	ParenthesizedGenericArguments,
+	ReturnType,
+	Generics,
+	GenericParam,
+	TraitItem,
+	Type,
+	TypeTuple,
+	parse_quote
+};
 use syn::punctuated::Punctuated;
 use syn::parse::{Result, Error};
 use quote::ToTokens;
@@ -12,11 +24,107 @@ use super::parameter_info::ParameterInfo;
 use super::parameter_value::ParameterValue;
 use super::partial_eval::PartialEval;
 
+// These can't be implemented on stable, so there's no point in trying.
+const UNSTABLE_TRAITS: [&str; 3] = ["Fn", "FnMut", "FnOnce"];
+
+fn check_stable_trait (trait_path: &Path) -> Result <()>
+{
+	match trait_path . segments . last ()
+	{
+		Some (segment)
+		if UNSTABLE_TRAITS . iter () . any (|unstable| segment . ident == unstable) =>
+			Err
+			(
+				Error::new_spanned
+				(
+					trait_path,
+					format!
+					(
+						"`{}` can't be implemented on stable Rust, so it can't be forwarded",
+						segment . ident
+					)
+				)
+			),
+		_ => Ok (())
+	}
+}
+
+// Rewrites `Trait (A, B) -> R` as `Trait <(A, B)>`, for traits that take a
+// single type parameter.  The `Output = R` binding is returned separately,
+// since it isn't allowed in the path of an implemented trait.  If no return
+// type is given, `R` is `()`, as long as the trait declares `Output` at all.
+pub fn desugar_trait_path
+(
+	trait_generics: &Generics,
+	trait_items: &[TraitItem],
+	mut trait_path: Path
+)
+-> Result <(Path, Option <Type>)>
+{
+	check_stable_trait (&trait_path)?;
+
+	let Some (segment) = trait_path . segments . last_mut ()
+	else { return Ok ((trait_path, None)); };
+
+	let PathArguments::Parenthesized (arguments) = &segment . arguments
+	else { return Ok ((trait_path, None)); };
+
+	let takes_single_type = matches!
+	(
+		trait_generics . params . iter () . collect::<Vec <_>> () . as_slice (),
+		[GenericParam::Type (_)]
+	);
+
+	if ! takes_single_type
+	{
+		return Err
+		(
+			Error::new_spanned
+			(
+				arguments,
+				"Parenthesized arguments can only be given to traits that take a single type parameter"
+			)
+		);
+	}
+
+	let declares_output = trait_items
+		. iter ()
+		. any (|item| matches! (item, TraitItem::Type (item_type) if item_type . ident == "Output"));
+
+	let ParenthesizedGenericArguments {paren_token, inputs, output} = arguments . clone ();
+
+	let mut elems = inputs;
+	if elems . len () == 1 { elems . push_punct (Default::default ()); }
+
+	let arguments_type = Type::Tuple (TypeTuple {paren_token, elems});
+
+	let output_type = match output
+	{
+		ReturnType::Type (_, output_type) => Some (*output_type),
+		ReturnType::Default if declares_output => Some (parse_quote! (())),
+		ReturnType::Default => None
+	};
+
+	segment . arguments = PathArguments::AngleBracketed (parse_quote! (<#arguments_type>));
+
+	Ok ((trait_path, output_type))
+}
+
 pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 -> Result <PartialEval>
 {
-	let trait_arguments = if let Some (segment) =
-		trait_path . segments . last ()
+	check_stable_trait (trait_path)?;
+
+	get_path_evaluator (trait_generics, trait_path, "Trait")
+}
+
+// Maps the generic parameters of the item that `path` names to the arguments
+// given in its last segment, falling back to the parameters' defaults.
+pub fn get_path_evaluator (generics: Generics, path: &Path, kind: &str)
+-> Result <PartialEval>
+{
+	let arguments = if let Some (segment) =
+		path . segments . last ()
 	{
 		match &segment . arguments
 		{
@@ -24,7 +132,11 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 				arguments . args . clone (),
 			PathArguments::Parenthesized (_) => return Err
 			(
-				Error::new_spanned (trait_path, "Fn* traits cannot be forwarded")
+				Error::new_spanned
+				(
+					path,
+					"Parenthesized arguments must be desugared before evaluation"
+				)
 			),
 			_ => Punctuated::new ()
 		}
@@ -33,13 +145,13 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 	{
 		return Err
 		(
-			Error::new_spanned (trait_path, "Path to trait must be nonempty")
+			Error::new_spanned (path, "Path must be nonempty")
 		);
 	};
 
-	let num_provided_arguments = trait_arguments . len ();
-	let num_available_arguments = trait_generics . params . len ();
-	let num_required_arguments = get_num_required_arguments (&trait_generics);
+	let num_provided_arguments = arguments . len ();
+	let num_available_arguments = generics . params . len ();
+	let num_required_arguments = get_num_required_arguments (&generics);
 
 	if num_provided_arguments < num_required_arguments
 	{
@@ -47,10 +159,11 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 		(
 			Error::new_spanned
 			(
-				trait_arguments,
+				arguments,
 				format!
 				(
-					"Trait requires {} arguments, {} were provided",
+					"{} requires {} arguments, {} were provided",
+					kind,
 					num_required_arguments,
 					num_provided_arguments
 				)
@@ -64,10 +177,11 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 		(
 			Error::new_spanned
 			(
-				trait_arguments,
+				arguments,
 				format!
 				(
-					"Trait only takes {} arguments, {} were provided",
+					"{} only takes {} arguments, {} were provided",
+					kind,
 					num_available_arguments,
 					num_provided_arguments
 				)
@@ -77,37 +191,37 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 
 	let mut evaluator = PartialEval::new ();
 
-	for (trait_parameter, trait_argument)
-	in trait_generics
+	for (parameter, argument)
+	in generics
 		. params
 		. iter ()
 		. cloned ()
 		. zip
 		(
-			trait_arguments
+			arguments
 				. into_iter ()
 				. map (Option::from)
 				. chain (repeat (None))
 		)
 	{
-		if let Some (trait_argument) = trait_argument
+		if let Some (argument) = argument
 		{
 			evaluator . parameters . insert
 			(
-				ParameterInfo::from (trait_parameter),
-				ParameterValue::try_from (trait_argument)?
+				ParameterInfo::from (parameter),
+				ParameterValue::try_from (argument)?
 			);
 		}
 		else
 		{
 			evaluator . parameters . insert
 			(
-				ParameterInfo::from (trait_parameter . clone ()),
+				ParameterInfo::from (parameter . clone ()),
 
 				// If someone somehow manages to mix the parameters in silly
 				// ways, attempting to pull the default arguments could still
 				// fail.
-				ParameterValue::try_from_default_value (trait_parameter)?
+				ParameterValue::try_from_default_value (parameter)?
 			);
 		}
 	}
@@ -136,7 +250,7 @@ pub fn get_trait_path_evaluator (trait_generics: Generics, trait_path: &Path)
 			(
 				Error::new_spanned
 				(
-					trait_generics,
+					generics,
 					"Iteration limit reached evaluating default arguments"
 				)
 			);
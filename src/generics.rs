@@ -1,4 +1,4 @@
-use syn::{Generics, WhereClause, GenericParam, Token};
+use syn::{Generics, WhereClause, GenericParam, Path, PathArguments, Ident, Type, Token, parse_quote};
 use syn::punctuated::Punctuated;
 
 pub fn combine_generics <const N: usize> (parts: [Generics; N]) -> Generics
@@ -51,3 +51,49 @@ pub fn get_num_required_arguments (generics: &Generics) -> usize
 
 	count
 }
+
+pub fn get_param_idents (generics: &Generics) -> Vec <Ident>
+{
+	generics
+		. params
+		. iter ()
+		. map
+		(
+			|param| match param
+			{
+				GenericParam::Lifetime (lifetime_param) =>
+					lifetime_param . lifetime . ident . clone (),
+				GenericParam::Type (type_param) => type_param . ident . clone (),
+				GenericParam::Const (const_param) => const_param . ident . clone ()
+			}
+		)
+		. collect ()
+}
+
+// Appends `Ident = Type` bindings to the arguments of the last segment of a
+// trait path.
+pub fn add_associated_type_bindings
+(
+	trait_path: &mut Path,
+	bindings: impl IntoIterator <Item = (Ident, Type)>
+)
+{
+	let mut bindings = bindings . into_iter () . peekable ();
+
+	if bindings . peek () . is_none () { return; }
+
+	let Some (last_segment) = trait_path . segments . last_mut () else { return; };
+
+	if let PathArguments::None = last_segment . arguments
+	{
+		last_segment . arguments = PathArguments::AngleBracketed (parse_quote! (<>));
+	}
+
+	if let PathArguments::AngleBracketed (arguments) = &mut last_segment . arguments
+	{
+		for (ident, ty) in bindings
+		{
+			arguments . args . push (parse_quote! (#ident = #ty));
+		}
+	}
+}
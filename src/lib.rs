@@ -171,6 +171,7 @@ mod fold;
 mod value_transformer;
 mod type_transformer;
 mod transformer;
+mod composite_transformer;
 
 mod macros;
 
@@ -210,6 +211,14 @@ The only consideration is that any types used for method arguments or associated
 constants should be named by their fully-qualified paths.  This will prevent
 name-resolution errors from occurring in the macro-generated implementations.
 
+Method arguments may be bound with any pattern that the trait definition
+accepts.  Forwarded implementations give each argument a fresh name, so the
+patterns themselves have no effect on forwarding.
+
+Forwarded implementations of unsafe methods make the delegated call in an
+`unsafe` block of its own, and return types are copied from the trait
+definition, so the generated code means the same thing in every edition.
+
 # Annotating Re-Exports
 
 When re-exporting a trait that has been annotated, the use statement that does
@@ -236,6 +245,29 @@ into the forwarding macro.  If the forwarding information isn't re-exported
 alongside the trait, it won't be properly located if a path to the un-annotated
 re-export is used in the forwarding macro.
 
+# Annotating Inherent Impl Blocks
+
+Applied to an inherent impl block, the attribute records the signatures of the
+block's public methods, which can then be forwarded by
+[`forward_inherent!`](forward_inherent!) without writing them out again.  As
+with traits, the types in these signatures should be named by their
+fully-qualified paths.  Only impl blocks of types without generic parameters are
+supported, and only one impl block of each type can be annotated.  Unlike with
+traits, the macro exported into the crate root is named with a hash of the impl
+block, so types of the same name in different modules can both have their impl
+blocks annotated.
+
+```rust
+# use forward_traits::forwardable;
+struct Inner (Vec <u32>);
+
+#[forwardable]
+impl Inner
+{
+	pub fn len (&self) -> usize { self . 0 . len () }
+}
+```
+
 */
 #[proc_macro_attribute]
 pub fn forwardable (attr: TokenStream, item: TokenStream) -> TokenStream
@@ -273,6 +305,21 @@ struct Foo
 
 Both regular structs and tuple-structs are supported.
 
+Whether or not the struct is `#[repr (transparent)]` is recorded as well, which
+allows member delegation to transform values in both directions (see
+[`forward_traits!`](forward_traits!)).
+
+Members can be marked with `#[pin]` to have pinned receivers projected onto them
+structurally (see [`forward_traits!`](forward_traits!)).  As with
+`pin-project`, this makes the struct `Unpin` only when its pinned members are,
+forbids implementing `Drop` for it, and rejects `#[repr (packed)]`.  The marker
+is removed from the emitted struct.
+
+Receivers can be referred to by path in [`forward_traits!`](forward_traits!),
+in the same way as traits, so they don't need to be in scope there.  As with
+trait definitions, the types of members should then be named by their
+fully-qualified paths.
+
 # Limitations
 
 The only types that are supported are structs.  Forwarding methods for enums can
@@ -390,38 +437,389 @@ The first part of the syntax is a specification of the base type and how to
 transform it into the delegated type.  There are two such transformations
 available.
 
- * Conversion: `Ident -> Type`.  The type named by the ident (it is expected to
-   be in the same scope as the macro invocation) is transformed into the
-   delegated type via conversion traits.  For arguments, the following types are
-   converted using the specified traits.
+ * Conversion: `Path -> Type`.  The type named by the path (it is expected to
+   be in scope at the macro invocation, or to be named by a path that leads to
+   it, like `crate::model::Account`) is transformed into the delegated type via
+   conversion traits.  For arguments, the following types are converted using
+   the specified traits.
 
    * `Self`: `std::convert::Into <DelegatedType>`
    * `&Self`: `std::convert::AsRef <DelegatedType>`
    * `&mut Self`: `std::convert::AsMut <DelegatedType>`
 
-   Besides these specific types, `Box`, `Option`, `Result`, tuple types, and
-   array types are also transformed if their contents are a transformable type.
-   All arguments of a convertible type are converted, not just the receiver.
+   Besides these specific types, `Box`, `Option`, `Result`, `Poll`,
+   `ControlFlow`, `Bound`, tuple types, and array types are also transformed if
+   their contents are a transformable type.  Both the `Ok` and `Err` sides of a
+   `Result` are transformed, as are both the `Break` and `Continue` sides of a
+   `ControlFlow`.  All arguments of a convertible type are converted, not just
+   the receiver.  These types (and `Pin`, `Rc`, and `Arc` below) are
+   recognised by their bare names, or through their modules of `std`, `core`
+   or `alloc` (as in `std::task::Poll`), but not through other paths.  Other
+   types of the same names that are used by their bare names are mistaken for
+   them.
 
    The return value may be converted as well, if it is a form of `Self` type.
    This uses the following trait.
 
    * `-> Self`: `std::convert::From <DelegatedType>`
 
-   Like with arguments, `Result` and `Box` forms are also transformed.
+   Like with arguments, `Box`, `Option`, `Result`, `Poll`, `ControlFlow`,
+   `Bound`, tuple, and array forms are also transformed.
 
    The conversion traits that are actually used need to be implemented for the
    base type.  Any conversion traits that are not used are not required.
 
- * Member access: `Ident . Ident|Index`.  The first ident is the same as with
+ * Copied conversion: `Path copy -> Type` or `Path clone -> Type`.  This is
+   the same as conversion, except that borrowed forms of `Self` are converted by
+   value from a copy of the receiver, rather than through `AsRef` and `AsMut`.
+   This is useful for value types that can't be borrowed as the delegated type.
+
+   * `&Self`: A copy of the receiver is converted with
+     `std::convert::Into <DelegatedType>`, and the delegated method borrows the
+     converted value.
+   * `&mut Self`: Like `&Self`, but after the delegated method returns, the
+     converted value is written back into the receiver with
+     `std::convert::From <DelegatedType>`.
+
+   The receiver is copied with `std::marker::Copy` when `copy` is given, and with
+   `std::clone::Clone` when `clone` is given.  The write-back is only possible
+   for `&mut Self` arguments that are not nested inside of other types, and
+   delegated methods can't return borrows of the converted value.
+
+ * Member access: `Path . Ident|Index`.  The path is the same as with
    conversion.  The `Ident|Index` names a member of the struct to delegate to.
    An ident is required in the case of a struct with named fields, and an index
    is required in the case of a tuple struct.
 
    `Self`, `&Self`, and `&mut Self` typed arguments are tranformed via member
-   access.  Like with conversion, `Box`, `Option`, `Result`, tuple types, and
-   array types are also transformed if they contain some form of `Self`.  Member
-   delegation cannot transform return values.
+   access.  Like with conversion, `Box`, `Option`, `Result`, `Poll`,
+   `ControlFlow`, `Bound`, tuple types, and array types are also transformed if
+   they contain some form of `Self`.  Member delegation cannot transform return
+   values, unless the receiver is transparent (see below).
+
+If the receiver is generic, the forwarded implementation is normally generic
+over all of its parameters.  The receiver's path may instead be given generic
+arguments, like `Wrapper <f64, Meters>` or `Wrapper <u32, U>` (with `U`
+introduced by `for <U>`), to forward for just those instantiations of it.  Any
+arguments left off are filled in with the parameters' defaults.
+
+```rust
+# use std::marker::PhantomData;
+use forward_traits::{forwardable, forward_receiver, forward_traits};
+
+#[forwardable]
+trait Describe
+{
+	fn describe (&self) -> String;
+}
+
+impl Describe for f64
+{
+	fn describe (&self) -> String { format! ("{:.1}", self) }
+}
+
+struct Meters;
+
+#[forward_receiver]
+struct Quantity <T, Unit = Meters>
+{
+	value: T,
+	unit: PhantomData <Unit>
+}
+
+forward_traits! (for Quantity <f64> . value impl Describe);
+
+assert_eq! (Quantity::<f64> {value: 1.0, unit: PhantomData} . describe (), "1.0");
+```
+
+### Transparent Receivers
+
+If the receiver is `#[repr (transparent)]` and every member other than the one
+being delegated to is a `PhantomData`, then member delegation can also turn
+values of the delegated type back into values of the receiver type.  Owned
+values are rebuilt with a struct expression, and `&` and `&mut` references are
+cast, as the two types are guaranteed to share a layout.  This allows `Self` (and the forms listed above) to be returned from
+forwarded methods, and callbacks to be handed `&Self` and `&mut Self` (see
+below).  Borrowed return values still can't be transformed.
+
+Note that this bypasses any invariants that the receiver maintains over its
+member, and that the generated code contains `unsafe` blocks.
+
+### Associated Constants
+
+Associated constants whose types mention `Self` are transformed using only
+constant expressions.  `Self` is rebuilt with a struct expression, so member
+delegation requires the other members to be `PhantomData`, though unlike for
+return values, the receiver needn't be transparent.  Conversion delegation
+isn't supported at all, as `From::from` can't be called in constant
+expressions.  `Option` and `Result` are matched on, and tuples are rebuilt.
+Arrays (`[Self; N]`) and references (`&'static Self`) require a transparent
+receiver, as they're reinterpreted rather than rebuilt.  Constants of any other
+types that mention `Self` are rejected.
+
+### Callbacks
+
+Arguments that are callbacks taking or returning transformable types are
+transformed as well.  The delegated implementation is handed a new closure which
+transforms its arguments back into the receiver's form, calls the original
+callback, and transforms the result into the delegated type's form.  The
+following callback types are recognized.
+
+ * `impl Fn (..) -> ..`, `impl FnMut (..) -> ..`, and `impl FnOnce (..) -> ..`
+ * `&dyn Fn* (..) -> ..` and `&mut dyn Fn* (..) -> ..`
+ * `Box <dyn Fn* (..) -> ..>`
+
+Transforming callback arguments back into the receiver's form requires either
+conversion delegation, in which case only owned values can be transformed using
+`std::convert::From`, or a transparent receiver.
+Callback arguments and return values that mention transformed types in any
+other form (like `Vec <Self>`) are rejected.
+
+Function pointers (`fn (Self) -> Self`) cannot be wrapped in a closure.  These
+are only transformed for transparent receivers, where `Self` appears directly or
+behind a pointer, as the receiver is then ABI-compatible with the delegated
+type.
+
+### Pinned Receivers
+
+Methods taking `self: Pin <&Self>` or `self: Pin <&mut Self>` (such as
+`Future::poll`) are forwarded by projecting the pin onto the delegated type.
+
+With member delegation, marking the member with `#[pin]` in the
+[`forward_receiver`](macro@forward_receiver) definition projects the pin
+structurally, so the member need not be `Unpin`.  Unmarked members are
+projected with `Pin::new`, which requires the member type to be `Unpin`.
+
+```rust
+# use forward_traits::{supply_forwarding_info_for_trait, forward_receiver, forward_traits};
+# supply_forwarding_info_for_trait!
+# (
+# 	std::future::Future,
+# 	trait
+# 	{
+# 		type Output;
+# 		fn poll (self: std::pin::Pin <&mut Self>, cx: &mut std::task::Context <'_>)
+# 		-> std::task::Poll <Self::Output>;
+# 	}
+# );
+#[forward_receiver]
+struct Labelled <F>
+{
+	label: String,
+	#[pin]
+	inner: F
+}
+
+forward_traits! (for Labelled . inner impl Future);
+```
+
+With conversion delegation, the pin is projected through `AsRef` or `AsMut`,
+which requires both the receiver and the delegated type to be `Unpin`.
+
+Other pinned forms of the receiver, like `Pin <Box <Self>>`, can't be
+projected, and are rejected.
+
+### Shared Receivers
+
+`Rc <Self>` and `Arc <Self>` (as receivers, arguments, or return values) are
+transformed with one of the following strategies, depending on the mode of
+delegation.
+
+ * Conversion: the value is taken out of the pointer with `unwrap_or_clone`,
+   converted, and moved into a new allocation.  This requires the type being
+   converted from to be `Clone`, and the result is never shared.
+ * Member access: the member is cloned out of the receiver and moved into a
+   new allocation.  This requires the member type to be `Clone`.  The member
+   is cloned even when the pointer is unique, since it can't be moved out of
+   a receiver that implements `Drop`.  Values can't be transformed back into
+   the receiver type.
+ * Transparent receivers: the pointer is cast, so the allocation is reused and
+   stays shared, in both directions.
+
+Shared pointers are only transformed if they point directly at a transformed
+type, and borrowed shared pointers (such as `self: &Rc <Self>`) are rejected,
+as they can't be converted without reinterpreting the pointer type itself.
+
+### Method Generics
+
+Bounds on a forwarded method's generics and where clause that mention `Self`
+have to hold for the delegated type for the delegated call to be made.
+
+ * Predicates that don't involve the method's generic parameters (such as
+   `where Self: Clone`) are added to the forwarded method's where clause, with
+   `Self` replaced by the delegated type.  A method can't be given bounds that
+   its trait's declaration doesn't imply, so this only works when the delegated
+   type doesn't depend on the implementation's generic parameters.  Otherwise,
+   the predicate is rejected, rather than restricting the whole
+   implementation.
+ * A generic parameter bounded by `Into <Self>` is instantiated with the
+   delegated type in the delegated call.  Its values are converted into `Self`
+   and then into the delegated type, so it can only appear in argument types.
+ * Likewise, a generic parameter bounded by `From <Self>` is instantiated with
+   the delegated type, and the delegated call's result is converted back into
+   `Self` and then into the parameter.  It can only appear in the return type.
+
+Other bounds that relate the method's generic parameters to `Self` can't be
+satisfied for the delegated type, and are rejected.
+
+```rust,compile_fail
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Compare
+{
+	fn same_as <O> (&self, other: &O) -> bool
+	where O: PartialEq <Self>;
+}
+# impl Compare for u32
+# {
+# 	fn same_as <O> (&self, other: &O) -> bool where O: PartialEq <Self> { other == self }
+# }
+
+#[forward_receiver]
+struct Id (u32);
+
+// Error: bounds on method generic parameters can only mention `Self` as
+// `Into <Self>` or `From <Self>`
+forward_traits! (for Id . 0 impl Compare);
+```
+
+### `impl Trait` Arguments
+
+Argument-position `impl Trait` types that mention transformed types are adapted
+into values that implement the same trait for the delegated type.
+
+ * `impl IntoIterator <Item = Self>` and `impl Iterator <Item = Self>`: the
+   iterator is mapped, transforming each item.
+ * `impl Into <Self>`: the argument is converted into `Self`, then transformed.
+ * `impl AsRef <Self>`: the argument is borrowed as `&Self`, transformed, and
+   passed along as a `Cow`.  This requires the delegated type to be `ToOwned`.
+
+Only one bound may mention transformed types.  `impl Iterator` arguments may
+have other bounds that the mapped iterator carries over from the one it maps
+(such as `Clone`, `Send`, or `ExactSizeIterator`), while other bounds on the
+rest are rejected, as they wouldn't hold for the adapted argument.  `impl Trait`
+types that mention transformed types through any other trait are rejected.
+
+### Composite Delegation
+
+Member access may name several members at once, as `Path . (Ident|Index, ...)`,
+or name a member to iterate over, as `Path . [Ident|Index]`.  The member that's
+iterated over must be an array, a slice, or a collection with a single type
+argument (like `Vec <T>`), and the elements are iterated over by value or by
+reference, the same way that the receiver is taken.  Each method is then called
+on every member or element in turn, while associated types and constants are
+taken from the first member (or the element type).  Every member's type has to
+agree with the first's on associated types.
+
+Arguments are passed along to every call, so arguments other than references are
+cloned, and `&mut` arguments are reborrowed.  Element delegation can only
+transform the receiver, so other arguments of the receiver type are rejected.
+
+Methods that return `()` are simply called on every delegate.  Methods that
+return values need a reducer for their results, which is given after the
+members as `reduce {method => reducer, -> Type => reducer, ...}`, where a
+reducer named for a method takes precedence over one named for its return type.
+The following reducers are built in, and call every delegate.
+
+ * `sum` and `product`: `Iterator::sum` and `Iterator::product`.
+ * `all` and `any`: the logical and or or of the results.
+ * `collect`: `Iterator::collect`, into the return type.
+
+There's also `first_ok`, for methods returning `Result` or `Option`, which only
+calls delegates until one returns `Ok` (or `Some`), and returns the last
+delegate's result otherwise.  It can only be used with several members, as an
+empty collection would leave no result to return.
+
+Any other path names a function which is called with an iterator of the
+results.
+
+```rust
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Counter
+{
+	fn tick (&mut self, by: u32);
+	fn count (&self) -> u32;
+}
+
+impl Counter for u32
+{
+	fn tick (&mut self, by: u32) { *self += by; }
+	fn count (&self) -> u32 { *self }
+}
+
+#[forward_receiver]
+struct Both {a: u32, b: u32}
+
+forward_traits! (for Both . (a, b) reduce {count => sum} impl Counter);
+
+#[forward_receiver]
+struct Each (Vec <u32>);
+
+fn largest (counts: impl Iterator <Item = u32>) -> u32
+{
+	counts . max () . unwrap_or (0)
+}
+
+forward_traits! (for Each . [0] reduce {-> u32 => largest} impl Counter);
+
+let mut both = Both {a: 1, b: 2};
+both . tick (1);
+assert_eq! (both . count (), 5);
+
+let mut each = Each (vec! [1, 4]);
+each . tick (1);
+assert_eq! (each . count (), 5);
+```
+
+Members may also be named as fallbacks for each other, as `Path . Ident|Index
+else Ident|Index else ...`.  Methods that return a `Result` or an `Option` are
+called on the first member, and called again on the next one if they return
+`Err` or `None`.  Arguments taken by value are cloned to do so.
+
+Any other method is forwarded to the first member only, without a fallback and
+without any warning.  This includes:
+
+ * Methods that return other types, including aliases like `io::Result <T>`.
+   `Result` and `Option` are only recognised by their bare names or through
+   `std` or `core`, as in `std::option::Option <T>`.
+ * Methods that take the receiver, or other arguments of the receiver type, by
+   value, as these can't be passed along twice.
+ * Methods with other arguments taken by value, unless their types are known to
+   be cloneable from their names alone.  These are the primitive types,
+   `String`, shared references, raw and function pointers, `Rc` and `Arc`, and
+   tuples, arrays, `Option`, `Box` and `Vec` of these.  Other types, even ones
+   that implement `Clone`, and the method's generic parameters, aren't
+   recognised.
+
+```rust
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Fetch
+{
+	fn fetch (&self, key: String) -> Option <u32>;
+}
+
+impl Fetch for Vec <(String, u32)>
+{
+	fn fetch (&self, key: String) -> Option <u32>
+	{
+		self . iter () . find (|(k, _)| *k == key) . map (|(_, v)| *v)
+	}
+}
+
+#[forward_receiver]
+struct Cached
+{
+	cache: Vec <(String, u32)>,
+	source: Vec <(String, u32)>
+}
+
+forward_traits! (for Cached . cache else source impl Fetch);
+
+let cached = Cached {cache: vec! [], source: vec! [("a" . to_string (), 1)]};
+assert_eq! (cached . fetch ("a" . to_string ()), Some (1));
+```
 
 ## Additional Transformations
 
@@ -431,11 +829,14 @@ for the base type transformation.  These specifications have a slightly
 different syntax, as they do not rely on type annotations like the base type
 transformation does.
 
- * Conversion: `Type -> Type`.  Instead of an ident in the first position, we
+ * Conversion: `Type -> Type`.  Instead of a path in the first position, we
    have a full type.  The behavior is otherwise the same.
 
+ * Copied conversion: `Type copy -> Type` or `Type clone -> Type`.  Again, the
+   only difference is the full type in the first position.
+
  * Member access: `Type . Ident|Index : Type`.  Not only do we have a type in
-   the first position instead of an ident, but the type of the member must also
+   the first position instead of a path, but the type of the member must also
    be provided.
 
 These transformations do not assume that the type on the left is in the same
@@ -459,7 +860,9 @@ the form `Self::TypeName`), then some additional things will happen.
 Associated types in forwarded trait implementations will be assigned to the type
 on the RHS of the additional transformation, rather than to the associated type
 provided by the delegated trait implementation.  Values of that associated type
-will be transformed, like with other transformations.
+will be transformed, like with other transformations.  This also applies where
+the trait definition spells the associated type out as `<Self as
+Trait>::TypeName`.
 
 If the associated type takes any lifetime parameters, then the LHS of the
 additional transformation should be prefixed with quanfitied lifetimes (`for
@@ -470,6 +873,17 @@ additional transformation looks like `for <Lifetime, ...> Self::TypeName
 Due to language limitations, it is generally not possible to transform
 associated types that take type or const parameters.
 
+Any bounds declared on the associated type in the trait definition (`type Item:
+Clone + Debug;`) are added to the where clause of the forwarded trait
+implementation as bounds on the RHS, quantified over the associated type's
+lifetime parameters, so that unsatisfied bounds are reported at the
+transformation.  Other associated types named in these bounds are replaced with
+the types they're assigned.  Outlives requirements in the associated type's where
+clause (`where Self: 'a`) are left out, so the bounds have to hold for every
+lifetime.  Bounds on associated types with type or const parameters, or with
+trait bounds in their where clauses, can't be expressed this way, and are
+rejected.
+
 ## Forwarded Traits
 
 Lastly, we have the actual traits to forward.
@@ -483,6 +897,13 @@ path to the trait with a `for <GenericParam, ...>` construction.  Keep in mind
 that any generic parameters provided as part of the transformation specification
 will also be in scope.
 
+Traits that take a single type parameter may also be given their argument in
+the parenthesized form used by the `Fn*` traits.  `Trait (A, B) -> R` is
+forwarded as `Trait <(A, B)>`, with the delegated implementation required to
+have `Output = R`.  If the return type is left out and the trait declares an
+`Output` associated type, it's taken to be `()`.  The `Fn*` traits themselves
+can't be implemented on stable Rust, and so can't be forwarded.
+
 Normally, the where clause is constructed by combining the where clause of on
 the type definition, the where clause provided with the transformation
 specifications, and the where clause on the trait definition, and then finally
@@ -492,11 +913,138 @@ beyond the computed set, however.  These additional predicates may be provided
 by appending a where clause to the forwarded trait specification.  If a where
 clause is appended, it must also be followed by a semi-colon (`;`).
 
+If the trait's items mention associated types of a supertrait (as `IndexMut`
+does with `Self::Output` from `Index`), the where clause also states that those
+associated types are the same as the delegated type's, under the assumption
+that the supertrait is forwarded with the same transformation.  Associated types
+written as `<Self as Supertrait>::TypeName` are tied through the supertrait they
+name, while for ones written as `Self::TypeName`, this is only done when the
+trait has a single supertrait (including marker traits like `Send`), so traits
+with several supertraits have to use the qualified form.  Associated types that
+are replaced by an additional transformation aren't tied, as they're
+transformed instead.
+
+Individual items of the trait can be forwarded to other members (or converted
+to other types) than the rest by listing them in braces after the trait, along
+with the base type transformation to use for them: `Trait {item, ... =>
+BaseTransformation; ...}`, where the base transformation is written without the
+receiver (like `. member` or `-> Type`).  Each delegated type must implement the
+trait, and must agree with the others on any associated types that aren't
+transformed.
+
+```rust
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Storage
+{
+	fn get (&self, key: usize) -> Option <u32>;
+	fn put (&mut self, value: u32);
+}
+
+impl Storage for Vec <u32>
+{
+	fn get (&self, key: usize) -> Option <u32> { <[u32]>::get (self, key) . copied () }
+	fn put (&mut self, value: u32) { self . push (value) }
+}
+
+#[forward_receiver]
+struct Store
+{
+	cache: Vec <u32>,
+	backend: Vec <u32>
+}
+
+forward_traits! (for Store . backend impl Storage {get => . cache});
+
+let mut store = Store {cache: vec! [1], backend: vec! []};
+store . put (2);
+assert_eq! (store . get (0), Some (1));
+assert_eq! (store . backend, vec! [2]);
+```
+
+Methods can also be forwarded to methods of other names, either of another trait
+(`method => OtherTrait::other_method`) or inherent to the delegated type
+(`method => other_method`).  The arguments are passed along as they would be to
+the method itself, and the delegated type is required to implement the named
+trait.  A delegated type that only receives renamed methods needn't implement
+the forwarded trait at all.
+
+Only the forwarded trait's signatures are known to `forward_traits!`, so the
+other method's signature isn't checked against them.  A mismatch is reported by
+the compiler at the call that's made to the other method, which is attributed
+to the method's path in the mapping.
+
+```rust
+# use std::collections::HashMap;
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait KeyValue
+{
+	fn lookup (&self, key: &str) -> Option <&u32>;
+	fn store (&mut self, key: String, value: u32) -> Option <u32>;
+}
+
+#[forward_receiver]
+struct Registry (HashMap <String, u32>);
+
+forward_traits! (for Registry . 0 impl KeyValue {lookup => get; store => insert});
+
+let mut registry = Registry (HashMap::new ());
+registry . store ("a" . to_string (), 1);
+assert_eq! (registry . lookup ("a"), Some (&1));
+```
+
+When the delegated type has inherent methods for the whole trait, appending
+`via inherent` to the trait forwards every method that isn't otherwise renamed
+to the inherent method of the same name, so a local trait can be implemented
+over a foreign type without any glue.
+
+Methods forwarded by ident (renamed, or `via inherent`) are called by path, as
+`<Delegated>::len (&self . 0)`.  Inherent methods of the delegated type take
+precedence, and a method that it doesn't have is an error at the call, unless a
+trait in scope has a method of that name.  Methods aren't found through
+auto-dereferencing, so the slice methods of a `Vec <T>`, for example, can't be
+forwarded this way.
+
+```rust,compile_fail
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Text
+{
+	fn size (&self) -> usize;
+}
+
+#[forward_receiver]
+struct Buffer (String);
+
+// `String` has no `length` method.
+forward_traits! (for Buffer . 0 impl Text {size => length});
+```
+
+```rust
+# use forward_traits::{forwardable, forward_receiver, forward_traits};
+#[forwardable]
+trait Text
+{
+	fn push_str (&mut self, text: &str);
+	fn size (&self) -> usize;
+}
+
+#[forward_receiver]
+struct Buffer (String);
+
+forward_traits! (for Buffer . 0 impl Text via inherent {size => len});
+
+let mut buffer = Buffer (String::new ());
+buffer . push_str ("abc");
+assert_eq! (buffer . size (), 3);
+```
+
 If all of these syntactic elements are required in the same specification, you
 can end up with something looking like this:
 
 ```rust,ignore
-for <GenericParam, ...> path::to::Trait <GenericArgument, ...> where WherePredicate, ...;
+for <GenericParam, ...> path::to::Trait <GenericArgument, ...> via inherent {Item, ... => BaseTransformation; method => path::to::method; ...} where WherePredicate, ...;
 ```
 
 ## Putting It All Together
@@ -534,6 +1082,24 @@ forward_traits!
 );
 ```
 
+Several receivers can be given the same traits at once by listing their base
+type transformations (each with its own additional transformations), separated
+by commas, in braces (`{}`).  The generic parameters and where clause are shared
+by all of them.  This is the same as one invocation per receiver.
+
+```rust,ignore
+forward_traits!
+(
+	for <GenericParam, ...>
+	{
+		BaseTransformation [AdditionalTransformation, ...],
+		...
+	}
+	where WherePredicate, ...
+	impl ForwardedTrait + ...
+);
+```
+
 # Examples
 
 Here we need to introduce a lifetime for one of our forwarded trait's generic
@@ -686,3 +1252,128 @@ pub fn forward_traits (input: TokenStream) -> TokenStream
 {
 	macros::forward_traits::forward_traits_impl (input)
 }
+
+#[doc (hidden)]
+#[proc_macro]
+pub fn __forward_methods (input: TokenStream) -> TokenStream
+{
+	macros::forward_methods::__forward_methods_impl (input)
+}
+
+/**
+
+This macro forwards inherent methods of a delegated type, generating an
+inherent implementation on the receiver.
+
+The receiver and its transformation are specified in the same way as for
+[`forward_traits!`](forward_traits!), followed by the signatures of the methods
+to forward in braces.  Each method is forwarded to the delegated type's inherent
+method of the same name, with its arguments and return value transformed as they
+would be for a trait method.  The visibility and attributes (such as doc
+comments) of each signature are kept on the generated method.
+
+```rust
+use forward_traits::{forward_receiver, forward_methods};
+
+#[forward_receiver]
+struct Names (Vec <String>);
+
+forward_methods!
+(
+	for Names . 0
+	{
+		/// The number of names.
+		pub fn len (&self) -> usize;
+
+		pub fn as_slice (&self) -> &[String];
+
+		pub fn push (&mut self, name: String);
+	}
+);
+
+let mut names = Names (Vec::new ());
+names . push ("Ada" . to_string ());
+
+assert_eq! (names . len (), 1);
+assert_eq! (names . as_slice (), ["Ada"]);
+```
+
+As with methods forwarded by ident in [`forward_traits!`](forward_traits!), the
+methods are looked up on the delegated type itself, and not on the types that it
+dereferences to, so slice methods of a `Vec <T>` can't be forwarded this way.
+
+As with traits, the types used in the signatures should be named by their
+fully-qualified paths, and `Self` refers to the receiver.
+
+*/
+#[proc_macro]
+pub fn forward_methods (input: TokenStream) -> TokenStream
+{
+	macros::forward_methods::forward_methods_impl (input)
+}
+
+#[doc (hidden)]
+#[proc_macro]
+pub fn __forward_inherent (input: TokenStream) -> TokenStream
+{
+	macros::forward_methods::__forward_inherent_impl (input)
+}
+
+/**
+
+This macro forwards inherent methods of a delegated type whose impl block has
+been annotated with [`#[forwardable]`](macro@forwardable), generating an inherent
+implementation on the receiver.
+
+The receiver and its transformation are specified in the same way as for
+[`forward_traits!`](forward_traits!).  After `impl` comes the path to the
+delegated type's definition, followed by the methods to forward in braces, as in
+`Type::{method, ...}`.  If the braces are left out, all of the public methods of
+the impl block are forwarded.  Several types can be given, separated by `+`.
+
+The forwarding information of an impl block isn't imported along with its type,
+so the path should name the type through the module that the impl block is in.
+
+Methods are forwarded as they are by [`forward_methods!`](forward_methods!), so
+changes to their signatures are picked up by every receiver that forwards them.
+
+```rust
+use forward_traits::{forwardable, forward_receiver, forward_inherent};
+
+struct Inner
+{
+	values: Vec <u32>
+}
+
+#[forwardable]
+impl Inner
+{
+	/// The number of values.
+	pub fn len (&self) -> usize { self . values . len () }
+
+	pub fn get (&self, index: usize) -> Option <&u32> { self . values . get (index) }
+
+	pub fn push (&mut self, value: u32) { self . values . push (value) }
+}
+
+#[forward_receiver]
+struct Wrapper
+{
+	inner: Inner
+}
+
+forward_inherent! (for Wrapper . inner impl Inner::{len, get, push});
+
+let mut wrapper = Wrapper {inner: Inner {values: Vec::new ()}};
+wrapper . push (7);
+
+assert_eq! (wrapper . len (), 1);
+assert_eq! (wrapper . get (0), Some (&7));
+```
+
+*/
+#[proc_macro]
+pub fn forward_inherent (input: TokenStream) -> TokenStream
+{
+	macros::forward_methods::forward_inherent_impl (input)
+}
@@ -0,0 +1,263 @@
+use syn::{Token, parse};
+use syn::punctuated::Punctuated;
+use syn::parse::{Result, Error};
+use syn::fold::Fold;
+use syn_derive::Parse;
+use quote::{quote, ToTokens};
+
+use crate::generics::combine_generics;
+
+use crate::syn
+::{
+	type_def_info::TypeDefInfo,
+	inherent_methods::{InherentMethod, InherentMethods, ForwardedInherentInfo}
+};
+
+use crate::fold::mangle::mangle_generics;
+
+use super::forward_traits
+::{
+	TypeTransformInfo,
+	TypeTransformInfos,
+	build_composite_transformer
+};
+
+#[derive (Parse)]
+struct ForwardMethods
+{
+	type_transform_info: TypeTransformInfo,
+	inherent_methods: InherentMethods
+}
+
+fn try_forward_methods_impl (input: proc_macro::TokenStream)
+-> Result <proc_macro2::TokenStream>
+{
+	let ForwardMethods {type_transform_info, inherent_methods} = parse (input)?;
+
+	let base_type_macro_path =
+		type_transform_info . base_type_transformer . get_type_macro_path ()?;
+
+	let tokens = quote!
+	{
+		#base_type_macro_path!
+		(
+			forward_traits::__forward_methods,
+			#type_transform_info #inherent_methods
+		);
+	};
+
+	Ok (tokens)
+}
+
+pub fn forward_methods_impl (input: proc_macro::TokenStream)
+-> proc_macro::TokenStream
+{
+	try_forward_methods_impl (input)
+		. unwrap_or_else (Error::into_compile_error)
+		. into ()
+}
+
+// Generates an inherent implementation on the receiver, forwarding each of the
+// methods to the delegates' inherent methods of the same names.
+pub fn forward_inherent_methods
+(
+	type_transform_info: TypeTransformInfo,
+	methods: Vec <InherentMethod>,
+	type_def_info: TypeDefInfo
+)
+-> Result <proc_macro2::TokenStream>
+{
+	let type_def_info = type_transform_info
+		. base_type_transformer
+		. instantiate (type_def_info)?;
+
+	let generics = combine_generics
+	([
+		type_def_info . generics . clone (),
+		type_transform_info . generics
+	]);
+
+	let (mut generics, mut mangler) = mangle_generics (generics);
+
+	let (base_type, mut transformer) = build_composite_transformer
+	(
+		type_transform_info . base_type_transformer,
+		type_transform_info . additional_type_transformers,
+		&type_def_info,
+		&mut mangler,
+		None
+	)?;
+
+	let mut items = Vec::new ();
+
+	for InherentMethod {attrs, vis, sig, semi_token} in methods
+	{
+		let sig = mangler . fold_signature (sig);
+
+		items . push
+		(
+			transformer . transform_inherent_method
+			(
+				InherentMethod {attrs, vis, sig, semi_token}
+			)?
+		);
+	}
+
+	let predicates = &mut generics . make_where_clause () . predicates;
+
+	for delegate_transformer in transformer . get_transformers ()
+	{
+		delegate_transformer . add_predicates (predicates);
+	}
+
+	let (impl_generics, _, where_clause) = generics . split_for_impl ();
+
+	let inherent_impl = quote!
+	{
+		impl #impl_generics #base_type
+		#where_clause
+		{
+			#(#items)*
+		}
+	};
+
+	Ok (inherent_impl)
+}
+
+#[allow (dead_code)]
+#[derive (Parse)]
+struct ForwardMethodsWithInfo
+{
+	type_transform_info: TypeTransformInfo,
+	inherent_methods: InherentMethods,
+	comma_token: Token! [,],
+
+	type_def_info: TypeDefInfo
+}
+
+fn try_forward_methods_with_info_impl (input: proc_macro::TokenStream)
+-> Result <proc_macro2::TokenStream>
+{
+	let ForwardMethodsWithInfo
+	{
+		type_transform_info,
+		inherent_methods,
+		type_def_info,
+		..
+	}
+		= parse (input)?;
+
+	forward_inherent_methods
+	(
+		type_transform_info,
+		inherent_methods . methods,
+		type_def_info
+	)
+}
+
+pub fn __forward_methods_impl (input: proc_macro::TokenStream)
+-> proc_macro::TokenStream
+{
+	try_forward_methods_with_info_impl (input)
+		. unwrap_or_else (Error::into_compile_error)
+		. into ()
+}
+
+#[allow (dead_code)]
+#[derive (Parse)]
+struct ForwardInherent
+{
+	type_transform_infos: TypeTransformInfos,
+	impl_token: Token! [impl],
+
+	#[parse (Punctuated::parse_separated_nonempty)]
+	forwarded_inherent_infos: Punctuated <ForwardedInherentInfo, Token! [+]>
+}
+
+fn try_forward_inherent_impl (input: proc_macro::TokenStream)
+-> Result <proc_macro2::TokenStream>
+{
+	let ForwardInherent
+	{
+		type_transform_infos: TypeTransformInfos {type_transform_infos},
+		forwarded_inherent_infos,
+		..
+	}
+		= parse (input)?;
+
+	let mut tokens = proc_macro2::TokenStream::new ();
+
+	for type_transform_info in type_transform_infos
+	{
+		let base_type_macro_path =
+			type_transform_info . base_type_transformer . get_type_macro_path ()?;
+
+		for forwarded_inherent_info in &forwarded_inherent_infos
+		{
+			let forwarded_inherent_macro_path =
+				forwarded_inherent_info . get_macro_path ()?;
+
+			quote!
+			{
+				#base_type_macro_path!
+				(
+					#forwarded_inherent_macro_path,
+					forward_traits::__forward_inherent,
+					#type_transform_info impl #forwarded_inherent_info
+				);
+			}
+				. to_tokens (&mut tokens);
+		}
+	}
+
+	Ok (tokens)
+}
+
+pub fn forward_inherent_impl (input: proc_macro::TokenStream)
+-> proc_macro::TokenStream
+{
+	try_forward_inherent_impl (input)
+		. unwrap_or_else (Error::into_compile_error)
+		. into ()
+}
+
+#[allow (dead_code)]
+#[derive (Parse)]
+struct ForwardInherentWithInfo
+{
+	type_transform_info: TypeTransformInfo,
+	impl_token: Token! [impl],
+	forwarded_inherent_info: ForwardedInherentInfo,
+	comma_token_0: Token! [,],
+
+	type_def_info: TypeDefInfo,
+	comma_token_1: Token! [,],
+
+	inherent_methods: InherentMethods
+}
+
+fn try_forward_inherent_with_info_impl (input: proc_macro::TokenStream)
+-> Result <proc_macro2::TokenStream>
+{
+	let ForwardInherentWithInfo
+	{
+		type_transform_info,
+		forwarded_inherent_info,
+		type_def_info,
+		inherent_methods,
+		..
+	}
+		= parse (input)?;
+
+	let methods = forwarded_inherent_info . select (inherent_methods . methods)?;
+
+	forward_inherent_methods (type_transform_info, methods, type_def_info)
+}
+
+pub fn __forward_inherent_impl (input: proc_macro::TokenStream)
+-> proc_macro::TokenStream
+{
+	try_forward_inherent_with_info_impl (input)
+		. unwrap_or_else (Error::into_compile_error)
+		. into ()
+}
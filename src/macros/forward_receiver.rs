@@ -1,8 +1,142 @@
-use syn::{ItemStruct, Token, parse};
+use syn
+::{
+	Attribute,
+	Ident,
+	Type,
+	Generics,
+	GenericParam,
+	Field,
+	ItemStruct,
+	Token,
+	parse_quote,
+	parse
+};
 use syn::parse::{Result, Error};
+use quote::{quote, format_ident, ToTokens};
 
 use crate::uncurry::{get_macro_ident, gen_uncurry_macro};
-use crate::syn::type_def_info::TypeDefInfo;
+use crate::syn::type_def_info
+::{
+	TypeDefInfo,
+	is_repr_transparent,
+	is_repr_packed,
+	is_pin_attribute
+};
+
+// Pinned members are projected structurally, which is only sound if the
+// receiver is never `Unpin` unless its pinned members are, if its `Drop`
+// implementation can't move them, and if they're never unaligned.
+fn gen_pin_guards
+(
+	attrs: &[Attribute],
+	ident: &Ident,
+	generics: &Generics,
+	pinned_fields: &[Field]
+)
+-> Result <proc_macro2::TokenStream>
+{
+	if is_repr_packed (attrs)
+	{
+		return Err
+		(
+			Error::new_spanned
+			(
+				ident,
+				"Pinned members are not supported on `#[repr (packed)]` structs"
+			)
+		);
+	}
+
+	let phantom_types: Vec <Type> = generics
+		. params
+		. iter ()
+		. filter_map
+		(
+			|param| match param
+			{
+				GenericParam::Lifetime (lifetime_param) =>
+				{
+					let lifetime = &lifetime_param . lifetime;
+					Some (parse_quote! (&#lifetime ()))
+				},
+				GenericParam::Type (type_param) =>
+				{
+					let ident = &type_param . ident;
+					Some (parse_quote! (*const #ident))
+				},
+				GenericParam::Const (_) => None
+			}
+		)
+		. collect ();
+
+	let pinned_idents = (0..pinned_fields . len ())
+		. map (|i| format_ident! ("__field_{}", i));
+	let pinned_types = pinned_fields . iter () . map (|field| &field . ty);
+
+	// The extra lifetime keeps the bound on the `Unpin` implementation from
+	// being trivially false when the pinned members are concrete types.
+	let mut pinned_generics = generics . clone ();
+	pinned_generics . params . insert (0, parse_quote! ('__pin));
+
+	let (_, pinned_type_generics, _) = pinned_generics . split_for_impl ();
+
+	let mut unpin_generics = pinned_generics . clone ();
+	unpin_generics
+		. make_where_clause ()
+		. predicates
+		. push
+		(
+			parse_quote!
+			(
+				__Pinned #pinned_type_generics: std::marker::Unpin
+			)
+		);
+
+	let (unpin_impl_generics, _, unpin_where_clause) =
+		unpin_generics . split_for_impl ();
+
+	let (impl_generics, type_generics, where_clause) =
+		generics . split_for_impl ();
+
+	let pinned_where_clause = &pinned_generics . where_clause;
+
+	let tokens = quote!
+	{
+		const _: () =
+		{
+			#[allow (dead_code)]
+			struct __Pinned #pinned_generics #pinned_where_clause
+			{
+				__phantom: std::marker::PhantomData
+				<
+					fn () -> (&'__pin (), #(#phantom_types,)*)
+				>,
+				#(#pinned_idents: #pinned_types),*
+			}
+
+			#[allow (private_bounds)]
+			impl #unpin_impl_generics std::marker::Unpin for #ident #type_generics
+			#unpin_where_clause
+			{
+			}
+
+			trait MustNotImplDrop {}
+
+			#[allow (drop_bounds)]
+			impl <T> MustNotImplDrop for T
+			where T: std::ops::Drop
+			{
+			}
+
+			impl #impl_generics MustNotImplDrop for #ident #type_generics
+			#where_clause
+			{
+			}
+		};
+	};
+
+	Ok (tokens)
+}
 
 fn try_forward_receiver_impl
 (
@@ -11,18 +145,44 @@ fn try_forward_receiver_impl
 )
 -> Result <proc_macro2::TokenStream>
 {
-	let ItemStruct {vis, ident, generics, fields, ..} = parse (item . clone ())?;
+	let mut item_struct: ItemStruct = parse (item)?;
+
+	let ItemStruct {attrs, vis, ident, generics, fields, ..} =
+		item_struct . clone ();
 
 	let macro_ident = get_macro_ident (&ident);
 
+	// `#[pin]` isn't a real attribute, so it's removed from the definition, but
+	// kept in the type info.
+	let pinned_fields: Vec <Field> = fields
+		. iter ()
+		. filter (|field| field . attrs . iter () . any (is_pin_attribute))
+		. cloned ()
+		. collect ();
+
+	for field in &mut item_struct . fields
+	{
+		field . attrs . retain (|attr| ! is_pin_attribute (attr));
+	}
+
 	let type_info = TypeDefInfo
 	{
+		repr_transparent: is_repr_transparent (&attrs),
 		type_token: <Token! [type]>::default (),
-		generics,
+		generics: generics . clone (),
 		fields
 	};
 
-	let mut tokens = proc_macro2::TokenStream::from (item);
+	let mut tokens = item_struct . to_token_stream ();
+
+	if ! pinned_fields . is_empty ()
+	{
+		tokens . extend
+		(
+			gen_pin_guards (&attrs, &ident, &generics, &pinned_fields)?
+		);
+	}
+
 	tokens . extend (gen_uncurry_macro (vis, macro_ident, type_info));
 
 	Ok (tokens)
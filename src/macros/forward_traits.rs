@@ -1,33 +1,38 @@
-use syn::{Generics, Token, parse_quote, parse};
+use syn::{Generics, TraitItem, Ident, Type, Path, Token, braced, parse_quote, parse};
+use syn::token::Brace;
 use syn::punctuated::Punctuated;
 use syn::parse::{Parse, ParseStream, Result, Error};
 use syn::fold::Fold;
 use syn_derive::Parse;
 use quote::{quote, ToTokens};
 
-use crate::generics::combine_generics;
+use crate::generics::{combine_generics, add_associated_type_bindings, get_param_idents};
 
 use crate::syn
 ::{
 	trait_def_info::TraitDefInfo,
 	type_def_info::TypeDefInfo,
 	forwarded_trait_info::ForwardedTraitInfo,
-	additional_type_transformers::AdditionalTypeTransformers
+	additional_type_transformers::AdditionalTypeTransformers,
+	from_type::FromType,
+	item_routes::{ItemRoute, ItemRouteTarget}
 };
 
 use crate::fold::mangle::mangle_generics;
-use crate::fold::evaluator::get_trait_path_evaluator;
+use crate::fold::partial_eval::PartialEval;
+use crate::fold::evaluator::{desugar_trait_path, get_trait_path_evaluator};
 
 use crate::type_transformer::base_type_transformer::BaseTypeTransformer;
 
-use crate::transformer::TransformerBuilder;
+use crate::transformer::{TransformerBuilder, Transformer};
+use crate::composite_transformer::CompositeTransformer;
 
-struct TypeTransformInfo
+pub struct TypeTransformInfo
 {
-	for_token: Token! [for],
-	generics: Generics,
-	base_type_transformer: BaseTypeTransformer,
-	additional_type_transformers: AdditionalTypeTransformers
+	pub for_token: Token! [for],
+	pub generics: Generics,
+	pub base_type_transformer: BaseTypeTransformer,
+	pub additional_type_transformers: AdditionalTypeTransformers
 }
 
 impl Parse for TypeTransformInfo
@@ -68,11 +73,75 @@ impl ToTokens for TypeTransformInfo
 	}
 }
 
+#[derive (Parse)]
+struct ReceiverTransformInfo
+{
+	base_type_transformer: BaseTypeTransformer,
+	additional_type_transformers: AdditionalTypeTransformers
+}
+
+// `for <..> {Receiver . member [..], ..} where ..` is shorthand for the same
+// generics and where clause applied to each receiver in turn.
+pub struct TypeTransformInfos
+{
+	pub type_transform_infos: Vec <TypeTransformInfo>
+}
+
+fn is_receiver_list (input: ParseStream) -> bool
+{
+	let fork = input . fork ();
+
+	fork . parse::<Token! [for]> () . is_ok ()
+		&& fork . parse::<Generics> () . is_ok ()
+		&& fork . peek (Brace)
+}
+
+impl Parse for TypeTransformInfos
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		if ! is_receiver_list (input)
+		{
+			let type_transform_info = input . parse ()?;
+
+			return Ok (Self {type_transform_infos: vec! [type_transform_info]});
+		}
+
+		let for_token: Token! [for] = input . parse ()?;
+
+		let mut generics: Generics = input . parse ()?;
+
+		let content;
+		braced! (content in input);
+		let receivers: Punctuated <ReceiverTransformInfo, Token! [,]> =
+			Punctuated::parse_terminated (&content)?;
+
+		generics . where_clause = input . parse ()?;
+
+		let type_transform_infos = receivers
+			. into_iter ()
+			. map
+			(
+				|receiver| TypeTransformInfo
+				{
+					for_token,
+					generics: generics . clone (),
+					base_type_transformer: receiver . base_type_transformer,
+					additional_type_transformers:
+						receiver . additional_type_transformers
+				}
+			)
+			. collect ();
+
+		Ok (Self {type_transform_infos})
+	}
+}
+
 #[allow (dead_code)]
 #[derive (Parse)]
 struct ForwardTraits
 {
-	type_transform_info: TypeTransformInfo,
+	type_transform_infos: TypeTransformInfos,
 	impl_token: Token! [impl],
 
 	#[parse (Punctuated::parse_separated_nonempty)]
@@ -84,32 +153,35 @@ fn try_forward_traits_impl (input: proc_macro::TokenStream)
 {
 	let ForwardTraits
 	{
-		type_transform_info,
+		type_transform_infos: TypeTransformInfos {type_transform_infos},
 		forwarded_traits,
 		..
 	}
 		= parse (input)?;
 
-	let base_type_macro_ident =
-		type_transform_info . base_type_transformer . get_type_macro_ident ();
-
 	let mut tokens = proc_macro2::TokenStream::new ();
 
-	for forwarded_trait_info in forwarded_traits
+	for type_transform_info in type_transform_infos
 	{
-		let forwarded_trait_macro_path =
-			forwarded_trait_info . get_macro_path ()?;
+		let base_type_macro_path =
+			type_transform_info . base_type_transformer . get_type_macro_path ()?;
 
-		quote!
+		for forwarded_trait_info in &forwarded_traits
 		{
-			#base_type_macro_ident!
-			(
-				#forwarded_trait_macro_path,
-				forward_traits::__forward_trait,
-				#type_transform_info impl #forwarded_trait_info
-			);
+			let forwarded_trait_macro_path =
+				forwarded_trait_info . get_macro_path ()?;
+
+			quote!
+			{
+				#base_type_macro_path!
+				(
+					#forwarded_trait_macro_path,
+					forward_traits::__forward_trait,
+					#type_transform_info impl #forwarded_trait_info
+				);
+			}
+				. to_tokens (&mut tokens);
 		}
-			. to_tokens (&mut tokens);
 	}
 
 	Ok (tokens)
@@ -138,6 +210,174 @@ struct ForwardTrait
 	trait_def_info: TraitDefInfo
 }
 
+fn get_item_ident (item: &TraitItem) -> Option <&Ident>
+{
+	match item
+	{
+		TraitItem::Const (item_const) => Some (&item_const . ident),
+		TraitItem::Fn (item_fn) => Some (&item_fn . sig . ident),
+		TraitItem::Type (item_type) => Some (&item_type . ident),
+		_ => None
+	}
+}
+
+fn check_item_routes
+(
+	routed_transformers: &[(Vec <Ident>, CompositeTransformer)],
+	renamed_methods: &[(Vec <Ident>, Path)],
+	trait_items: &[TraitItem]
+)
+-> Result <()>
+{
+	let mut routed_items: Vec <&Ident> = Vec::new ();
+
+	let renamed_items = renamed_methods
+		. iter ()
+		. flat_map (|(renamed_items, _)| renamed_items)
+		. map (|renamed_item| (renamed_item, true));
+
+	for (routed_item, is_renamed) in routed_transformers
+		. iter ()
+		. flat_map (|(routed_items, ..)| routed_items)
+		. map (|routed_item| (routed_item, false))
+		. chain (renamed_items)
+	{
+		let Some (trait_item) = trait_items
+			. iter ()
+			. find (|item| get_item_ident (item) == Some (routed_item))
+		else
+		{
+			return Err
+			(
+				Error::new_spanned (routed_item, "The trait has no item of this name")
+			);
+		};
+
+		if is_renamed && ! matches! (trait_item, TraitItem::Fn (_))
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					routed_item,
+					"Only methods can be forwarded to items of other names"
+				)
+			);
+		}
+
+		if routed_items . contains (&routed_item)
+		{
+			return Err
+			(
+				Error::new_spanned (routed_item, "Trait items can only be routed once")
+			);
+		}
+
+		routed_items . push (routed_item);
+	}
+
+	Ok (())
+}
+
+// Builds the transformer for one of the delegates of the receiver, along with
+// the receiver type.
+fn build_transformer
+(
+	base_type_transformer: BaseTypeTransformer,
+	additional_type_transformers: AdditionalTypeTransformers,
+	type_def_info: &TypeDefInfo,
+	mangler: &mut PartialEval,
+	forwarded_trait: Option <&Path>
+)
+-> Result <(Type, Transformer)>
+{
+	let mut transformer_builder = TransformerBuilder::new ();
+
+	// The name of this method sucks, in context.
+	let (base_type, delegated_type, independent_type_transformer) =
+		base_type_transformer . into_type_transformer (type_def_info)?;
+
+	let independent_type_transformer = mangler
+		. fold_independent_type_transformer (independent_type_transformer);
+	let base_type = mangler . fold_type (base_type);
+	let delegated_type = mangler . fold_type (delegated_type);
+
+	transformer_builder . add_independent_type_transformer
+	(
+		independent_type_transformer
+	);
+
+	for additional_type_transformer in additional_type_transformers
+	{
+		// Associated types can only be transformed as items of the forwarded
+		// trait.
+		if let (None, FromType::Associated (associated_type)) =
+			(forwarded_trait, &additional_type_transformer . from_type)
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					associated_type,
+					"Associated types can only be transformed when forwarding a trait"
+				)
+			);
+		}
+
+		let additional_type_transformer =
+			mangler . fold_additional_type_transformer (additional_type_transformer);
+		transformer_builder . add_additional_type_transformer (additional_type_transformer);
+	}
+
+	let transformer = transformer_builder . into_transformer
+	(
+		delegated_type,
+		forwarded_trait . cloned ()
+	);
+
+	Ok ((base_type, transformer))
+}
+
+// Builds the transformers for one of the ways that the receiver is
+// transformed, which may delegate to several delegates at once.
+pub fn build_composite_transformer
+(
+	base_type_transformer: BaseTypeTransformer,
+	additional_type_transformers: AdditionalTypeTransformers,
+	type_def_info: &TypeDefInfo,
+	mangler: &mut PartialEval,
+	forwarded_trait: Option <&Path>
+)
+-> Result <(Type, CompositeTransformer)>
+{
+	let (base_type_transformers, composition) =
+		base_type_transformer . split_composite ()?;
+
+	let mut base_type = None;
+	let mut transformers = Vec::new ();
+
+	for base_type_transformer in base_type_transformers
+	{
+		let (delegate_base_type, transformer) = build_transformer
+		(
+			base_type_transformer,
+			additional_type_transformers . clone (),
+			type_def_info,
+			mangler,
+			forwarded_trait
+		)?;
+
+		base_type = Some (delegate_base_type);
+		transformers . push (transformer);
+	}
+
+	let composite_transformer =
+		CompositeTransformer::new (transformers, composition . fold_types (mangler));
+
+	// There's always at least one delegate.
+	Ok ((base_type . unwrap (), composite_transformer))
+}
+
 fn try_forward_trait_impl (input: proc_macro::TokenStream)
 -> Result <proc_macro2::TokenStream>
 {
@@ -151,6 +391,10 @@ fn try_forward_trait_impl (input: proc_macro::TokenStream)
 	}
 		= parse (input)?;
 
+	let type_def_info = type_transform_info
+		. base_type_transformer
+		. instantiate (type_def_info)?;
+
 	let generics = combine_generics
 	([
 		type_def_info . generics . clone (),
@@ -159,69 +403,258 @@ fn try_forward_trait_impl (input: proc_macro::TokenStream)
 	]);
 
 	let (mut generics, mut mangler) = mangle_generics (generics);
+	let impl_params = get_param_idents (&generics);
 
-	let forwarded_trait =
-		mangler . fold_path (forwarded_trait_info . trait_path);
+	let (forwarded_trait, forwarded_trait_output) = desugar_trait_path
+	(
+		&trait_def_info . generics,
+		&trait_def_info . items,
+		forwarded_trait_info . trait_path
+	)?;
 
-	let mut transformer_builder = TransformerBuilder::new ();
+	let forwarded_trait = mangler . fold_path (forwarded_trait);
+	let forwarded_trait_output = forwarded_trait_output
+		. map (|output_type| mangler . fold_type (output_type));
 
-	// The name of this method sucks, in context.
-	let (base_type, delegated_type, independent_type_transformer) =
-		type_transform_info . base_type_transformer . into_type_transformer
+	let mut routed_transformers = Vec::new ();
+	let mut renamed_methods = Vec::new ();
+
+	for ItemRoute {items: routed_items, target, ..}
+	in forwarded_trait_info . item_routes
+	{
+		let routed_items = routed_items . into_iter () . collect::<Vec <Ident>> ();
+
+		let transform_type = match target
+		{
+			ItemRouteTarget::Transform (transform_type) => transform_type,
+			ItemRouteTarget::Method (method) =>
+			{
+				renamed_methods . push ((routed_items, mangler . fold_path (method)));
+				continue;
+			}
+		};
+
+		let (_, mut routed_transformer) = build_composite_transformer
 		(
-			&type_def_info . generics,
-			&type_def_info . fields
+			type_transform_info
+				. base_type_transformer
+				. with_transform_type (transform_type),
+			type_transform_info . additional_type_transformers . clone (),
+			&type_def_info,
+			&mut mangler,
+			Some (&forwarded_trait)
 		)?;
 
-	let independent_type_transformer = mangler
-		. fold_independent_type_transformer (independent_type_transformer);
-	let base_type = mangler . fold_type (base_type);
-	let delegated_type = mangler . fold_type (delegated_type);
+		routed_transformer . set_impl_params (&impl_params);
+		routed_transformers . push ((routed_items, routed_transformer));
+	}
 
-	transformer_builder . add_independent_type_transformer
+	let (base_type, mut transformer) = build_composite_transformer
 	(
-		independent_type_transformer
-	);
+		type_transform_info . base_type_transformer,
+		type_transform_info . additional_type_transformers,
+		&type_def_info,
+		&mut mangler,
+		Some (&forwarded_trait)
+	)?;
 
-	for additional_type_transformer
-	in type_transform_info . additional_type_transformers
-	{
-		let additional_type_transformer =
-			mangler . fold_additional_type_transformer (additional_type_transformer);
-		transformer_builder . add_additional_type_transformer (additional_type_transformer);
-	}
+	transformer . set_impl_params (&impl_params);
 
-	let mut transformer = transformer_builder . into_transformer
-	(
-		delegated_type . clone (),
-		forwarded_trait . clone ()
-	);
+	let trait_where_clause = trait_def_info . generics . where_clause . clone ();
 
 	let mut evaluator =
 		get_trait_path_evaluator (trait_def_info . generics, &forwarded_trait)?;
 
-	let mut items = Vec::new ();
+	let trait_where_clause = trait_where_clause
+		. map (|where_clause| evaluator . fold_where_clause (where_clause));
 
-	for item in trait_def_info
+	let trait_items: Vec <TraitItem> = trait_def_info
 		. items
 		. into_iter ()
 		. map (|item| evaluator . fold_trait_item (item))
+		. collect ();
+
+	check_item_routes (&routed_transformers, &renamed_methods, &trait_items)?;
+
+	// Methods that aren't renamed otherwise are forwarded to the inherent
+	// methods of the same names.
+	if forwarded_trait_info . via_inherent . is_set ()
 	{
-		items . push (transformer . transform_trait_item (item)?);
+		for item in &trait_items
+		{
+			let TraitItem::Fn (item_fn) = item else { continue; };
+			let ident = &item_fn . sig . ident;
+
+			if renamed_methods
+				. iter ()
+				. any (|(renamed_items, _)| renamed_items . contains (ident))
+			{
+				continue;
+			}
+
+			let method: Path = ident . clone () . into ();
+
+			for (_, routed_transformer) in &mut routed_transformers
+			{
+				routed_transformer . rename_method (ident . clone (), method . clone ());
+			}
+
+			transformer . rename_method (ident . clone (), method);
+		}
 	}
 
-	// The transformer transforms the forwarded trait as a side-effect of being
-	// constructed.
-	let transformed_forwarded_trait =
-		transformer . get_transformed_forwarded_trait ();
+	for (renamed_items, method) in renamed_methods
+	{
+		for renamed_item in renamed_items
+		{
+			transformer . rename_method (renamed_item, method . clone ());
+		}
+	}
 
+	// Which of the routed transformers each of the trait's items is forwarded
+	// through, if any.
+	let item_routes: Vec <Option <usize>> = trait_items
+		. iter ()
+		. map
+		(
+			|item| get_item_ident (item) . and_then
+			(
+				|ident| routed_transformers
+					. iter ()
+					. position (|(routed_items, ..)| routed_items . contains (ident))
+			)
+		)
+		. collect ();
+
+	let get_routed_items = |route| trait_items
+		. iter ()
+		. zip (&item_routes)
+		. filter (|(_, item_route)| **item_route == route)
+		. map (|(item, _)| item . clone ())
+		. collect::<Vec <TraitItem>> ();
+
+	for (route, (_, routed_transformer)) in routed_transformers . iter_mut () . enumerate ()
 	{
-		let predicates = &mut generics . make_where_clause () . predicates;
-		predicates . push
+		routed_transformer . add_supertrait_predicates
 		(
-			parse_quote! (#delegated_type: #transformed_forwarded_trait)
+			trait_where_clause . as_ref (),
+			&get_routed_items (Some (route))
 		);
-		transformer . add_predicates (predicates);
+	}
+
+	transformer . add_supertrait_predicates
+	(
+		trait_where_clause . as_ref (),
+		&get_routed_items (None)
+	);
+
+	let mut items = Vec::new ();
+
+	// Which of the transformers each of the trait's associated types is
+	// assigned through, by index into the routed transformers.
+	let mut associated_type_routes = Vec::new ();
+
+	for (item, route) in trait_items . into_iter () . zip (item_routes)
+	{
+		if let TraitItem::Type (item_type) = &item
+		{
+			if item_type . generics . params . is_empty ()
+			{
+				associated_type_routes . push ((item_type . ident . clone (), route));
+			}
+		}
+
+		let item_transformer = match route
+		{
+			Some (route) => &mut routed_transformers [route] . 1,
+			None => &mut transformer
+		};
+
+		items . push (item_transformer . transform_trait_item (item)?);
+	}
+
+	// Values of associated types that aren't transformed pass straight through,
+	// so every delegated type has to agree on them with the one that they're
+	// assigned through.
+	let associated_type_owners: Vec <(Ident, Type, Path)> = associated_type_routes
+		. into_iter ()
+		. filter_map
+		(
+			|(ident, route)|
+			{
+				let owner = match route
+				{
+					Some (route) => &routed_transformers [route] . 1,
+					None => &transformer
+				};
+
+				Some
+				((
+					ident,
+					owner . get_delegated_type () . clone (),
+					owner . get_transformed_forwarded_trait ()? . clone ()
+				))
+			}
+		)
+		. collect ();
+
+	let predicates = &mut generics . make_where_clause () . predicates;
+
+	let composite_transformers = routed_transformers
+		. iter ()
+		. map (|(_, routed_transformer)| routed_transformer)
+		. chain ([&transformer]);
+
+	for composite_transformer in composite_transformers
+	{
+		for delegate_transformer in composite_transformer . get_transformers ()
+		{
+			// Delegates that only have methods forwarded to methods of other
+			// names don't need to implement the forwarded trait.  The
+			// transformer transforms the forwarded trait as a side-effect of
+			// being constructed.
+			let forwarded_trait = delegate_transformer
+				. get_transformed_forwarded_trait ()
+				. filter (|_| composite_transformer . uses_forwarded_trait ());
+
+			if let Some (forwarded_trait) = forwarded_trait
+			{
+				let delegated_type = delegate_transformer . get_delegated_type ();
+
+				let mut transformed_forwarded_trait = forwarded_trait . clone ();
+
+				// Parenthesized arguments pin down the output of the delegated
+				// implementation, which the forwarded implementation then
+				// assumes.
+				let bindings = associated_type_owners
+					. iter ()
+					. filter
+					(
+						|(ident, owner_type, _)| owner_type != delegated_type
+							&& ! delegate_transformer . transforms_associated_type (ident)
+					)
+					. map
+					(
+						|(ident, owner_type, owner_trait)|
+							(ident . clone (), parse_quote! (<#owner_type as #owner_trait>::#ident))
+					)
+					. chain
+					(
+						forwarded_trait_output
+							. clone ()
+							. map (|output_type| (parse_quote! (Output), output_type))
+					);
+
+				add_associated_type_bindings (&mut transformed_forwarded_trait, bindings);
+
+				predicates . push
+				(
+					parse_quote! (#delegated_type: #transformed_forwarded_trait)
+				);
+			}
+
+			delegate_transformer . add_predicates (predicates);
+		}
 	}
 
 	let (impl_generics, _, where_clause) = generics . split_for_impl ();
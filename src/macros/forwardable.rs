@@ -1,11 +1,32 @@
-use syn::{Attribute, Visibility, ItemTrait, ItemUse, Token, parse};
+use syn
+::{
+	Attribute,
+	Visibility,
+	ItemTrait,
+	ItemUse,
+	ItemImpl,
+	Ident,
+	Type,
+	Token,
+	parse,
+	parse_quote
+};
 use syn::parse::{Result, Error};
 use syn::fold::Fold;
 use syn_derive::Parse;
 use quote::ToTokens;
 
-use crate::uncurry::{get_macro_ident, gen_uncurry_macro};
+use crate::uncurry
+::{
+	get_macro_ident,
+	get_inherent_macro_ident,
+	get_inherent_mangled_ident,
+	get_path_ident,
+	gen_uncurry_macro,
+	gen_uncurry_macro_as
+};
 use crate::syn::trait_def_info::TraitDefInfo;
+use crate::syn::inherent_methods::InherentMethods;
 use crate::fold::transform_use::TransformUse;
 
 #[derive (Parse)]
@@ -20,10 +41,54 @@ use crate::fold::transform_use::TransformUse;
 enum Forwardable
 {
 	#[parse (peek = Token! [trait])]
-	ItemTrait (ItemTrait),
+	Trait (ItemTrait),
 
 	#[parse (peek = Token! [use])]
-	ItemUse (ItemUse)
+	Use (ItemUse),
+
+	#[parse (peek = Token! [impl])]
+	Impl (ItemImpl)
+}
+
+fn get_impl_ident (item_impl: &ItemImpl) -> Result <Ident>
+{
+	if let Some ((_, trait_path, _)) = &item_impl . trait_
+	{
+		return Err
+		(
+			Error::new_spanned
+			(
+				trait_path,
+				"Only inherent impl blocks can be made forwardable"
+			)
+		);
+	}
+
+	if ! item_impl . generics . params . is_empty ()
+	{
+		return Err
+		(
+			Error::new_spanned
+			(
+				&item_impl . generics,
+				"Generic impl blocks can't be made forwardable"
+			)
+		);
+	}
+
+	match &*item_impl . self_ty
+	{
+		Type::Path (type_path) if type_path . qself . is_none () =>
+			get_path_ident (&type_path . path),
+		self_ty => Err
+		(
+			Error::new_spanned
+			(
+				self_ty,
+				"The type of a forwardable impl block must be named by a path"
+			)
+		)
+	}
 }
 
 fn try_forwardable_impl
@@ -37,7 +102,7 @@ fn try_forwardable_impl
 
 	match parse (item)?
 	{
-		Forwardable::ItemTrait (item_trait) =>
+		Forwardable::Trait (item_trait) =>
 		{
 			let vis = item_trait . vis . clone ();
 
@@ -50,7 +115,27 @@ fn try_forwardable_impl
 				gen_uncurry_macro (vis, macro_ident, trait_def_info)
 			);
 		},
-		Forwardable::ItemUse (item_use) =>
+		Forwardable::Impl (item_impl) =>
+		{
+			let macro_ident = get_inherent_macro_ident (&get_impl_ident (&item_impl)?);
+			let mangled_ident = get_inherent_mangled_ident (&macro_ident, &item_impl);
+
+			let inherent_methods = InherentMethods::from_impl_items (&item_impl . items);
+
+			// The public methods can be forwarded from anywhere that the type
+			// can be named.
+			tokens . extend
+			(
+				gen_uncurry_macro_as
+				(
+					parse_quote! (pub),
+					macro_ident,
+					mangled_ident,
+					inherent_methods
+				)
+			);
+		},
+		Forwardable::Use (item_use) =>
 		{
 			TransformUse {}
 				. fold_item_use (item_use)
@@ -2,3 +2,4 @@ pub mod forwardable;
 pub mod supply_trait_info;
 pub mod forward_receiver;
 pub mod forward_traits;
+pub mod forward_methods;
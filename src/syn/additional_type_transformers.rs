@@ -6,6 +6,7 @@ use quote::ToTokens;
 
 use crate::type_transformer::additional_type_transformer::AdditionalTypeTransformer;
 
+#[derive (Clone)]
 pub struct AdditionalTypeTransformers
 {
 	bracket_token: Option <Bracket>,
@@ -1,8 +1,8 @@
-use syn::{Ident, Generics, Type, Token, parse2};
+use syn::{Ident, Generics, Type, Path, Token, parse2};
 use syn_derive::{Parse, ToTokens};
 use quote::ToTokens;
 
-#[derive (Parse, ToTokens)]
+#[derive (Clone, Parse, ToTokens)]
 pub struct AssociatedType
 {
 	pub self_token: Token! [Self],
@@ -17,4 +17,61 @@ impl AssociatedType
 	{
 		parse2 (ty . to_token_stream ()) . ok ()
 	}
+
+	// Also matches `<Self as Trait>::Name`, as long as `Trait` is the given
+	// trait.  Traits are compared by their last path segment.
+	pub fn match_type_of (ty: &Type, trait_path: &Path) -> Option <Self>
+	{
+		Self::match_type (ty) . or_else
+		(
+			|| QualifiedAssociatedType::match_type (ty)
+				. filter (|qualified| qualified . is_of (trait_path))
+				. map (Self::from)
+		)
+	}
+}
+
+#[derive (Parse, ToTokens)]
+pub struct QualifiedAssociatedType
+{
+	pub l_angle_token: Token! [<],
+	pub self_token: Token! [Self],
+	pub as_token: Token! [as],
+	pub trait_path: Path,
+	pub r_angle_token: Token! [>],
+	pub double_colon_token: Token! [::],
+	pub ident: Ident,
+	pub generics: Generics
+}
+
+impl QualifiedAssociatedType
+{
+	pub fn match_type (ty: &Type) -> Option <Self>
+	{
+		parse2 (ty . to_token_stream ()) . ok ()
+	}
+
+	pub fn is_of (&self, trait_path: &Path) -> bool
+	{
+		match (self . trait_path . segments . last (), trait_path . segments . last ())
+		{
+			(Some (segment), Some (trait_segment)) =>
+				segment . ident == trait_segment . ident,
+			_ => false
+		}
+	}
+}
+
+impl From <QualifiedAssociatedType> for AssociatedType
+{
+	fn from (qualified: QualifiedAssociatedType) -> Self
+	{
+		Self
+		{
+			self_token: qualified . self_token,
+			double_colon_token: qualified . double_colon_token,
+			ident: qualified . ident,
+			generics: qualified . generics
+		}
+	}
 }
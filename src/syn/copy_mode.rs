@@ -0,0 +1,13 @@
+use syn_derive::{Parse, ToTokens};
+
+use super::kw;
+
+#[derive (Clone, Copy, Parse, ToTokens)]
+pub enum CopyMode
+{
+	#[parse (peek = kw::copy)]
+	Copy (kw::copy),
+
+	#[parse (peek = kw::clone)]
+	Clone (kw::clone)
+}
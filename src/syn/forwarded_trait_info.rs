@@ -1,14 +1,20 @@
-use syn::{Generics, Path, Token};
+use syn::{Generics, Path, PathArguments, Token};
+use syn::token::Paren;
 use syn::parse::{Parse, ParseStream, Result};
 use quote::ToTokens;
 
 use crate::uncurry::get_macro_path;
 
+use super::item_routes::ItemRoutes;
+use super::via_inherent::ViaInherent;
+
 pub struct ForwardedTraitInfo
 {
 	pub for_token: Option <Token! [for]>,
 	pub generics: Generics,
 	pub trait_path: Path,
+	pub via_inherent: ViaInherent,
+	pub item_routes: ItemRoutes,
 	pub semi_token: Option <Token! [;]>
 }
 
@@ -29,7 +35,24 @@ impl Parse for ForwardedTraitInfo
 			if for_token . is_some () { input . parse ()? }
 			else { Generics::default () };
 
-		let trait_path = input . parse ()?;
+		let mut trait_path: Path = input . parse ()?;
+
+		// Parenthesized arguments (`Trait (A, B) -> R`) aren't parsed as part
+		// of a path outside of types.
+		if input . peek (Paren)
+		{
+			if let Some (segment) = trait_path . segments . last_mut ()
+			{
+				if let PathArguments::None = segment . arguments
+				{
+					segment . arguments = PathArguments::Parenthesized (input . parse ()?);
+				}
+			}
+		}
+
+		let via_inherent = input . parse ()?;
+
+		let item_routes = input . parse ()?;
 
 		generics . where_clause = input . parse ()?;
 
@@ -39,7 +62,18 @@ impl Parse for ForwardedTraitInfo
 		}
 		else { None };
 
-		Ok (ForwardedTraitInfo {for_token, generics, trait_path, semi_token})
+		Ok
+		(
+			ForwardedTraitInfo
+			{
+				for_token,
+				generics,
+				trait_path,
+				via_inherent,
+				item_routes,
+				semi_token
+			}
+		)
 	}
 }
 
@@ -56,6 +90,10 @@ impl ToTokens for ForwardedTraitInfo
 
 		self . trait_path . to_tokens (tokens);
 
+		self . via_inherent . to_tokens (tokens);
+
+		self . item_routes . to_tokens (tokens);
+
 		self . generics . where_clause . to_tokens (tokens);
 
 		self . semi_token . to_tokens (tokens);
@@ -4,7 +4,7 @@ use syn_derive::ToTokens;
 
 use super::associated_type::AssociatedType;
 
-#[derive (ToTokens)]
+#[derive (Clone, ToTokens)]
 pub enum FromType
 {
 	Independent (Type),
@@ -0,0 +1,234 @@
+use syn
+::{
+	Attribute,
+	Visibility,
+	Signature,
+	FnArg,
+	ImplItem,
+	Ident,
+	Path,
+	PathSegment,
+	Token,
+	braced
+};
+use syn::token::Brace;
+use syn::punctuated::Punctuated;
+use syn::parse::{Parse, ParseStream, Result, Error};
+use quote::{ToTokens, TokenStreamExt};
+
+use crate::uncurry::get_inherent_macro_path;
+
+// `#[doc = ".."] pub fn method (..) -> ..;`, the signature of an inherent
+// method to forward.
+pub struct InherentMethod
+{
+	pub attrs: Vec <Attribute>,
+	pub vis: Visibility,
+	pub sig: Signature,
+	pub semi_token: Token! [;]
+}
+
+impl Parse for InherentMethod
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		let attrs = input . call (Attribute::parse_outer)?;
+		let vis = input . parse ()?;
+		let sig = input . parse ()?;
+		let semi_token = input . parse ()?;
+
+		Ok (Self {attrs, vis, sig, semi_token})
+	}
+}
+
+impl ToTokens for InherentMethod
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		tokens . append_all (&self . attrs);
+		self . vis . to_tokens (tokens);
+		self . sig . to_tokens (tokens);
+		self . semi_token . to_tokens (tokens);
+	}
+}
+
+pub struct InherentMethods
+{
+	pub brace_token: Brace,
+	pub methods: Vec <InherentMethod>
+}
+
+impl Parse for InherentMethods
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		let content;
+		let brace_token = braced! (content in input);
+
+		let mut methods = Vec::new ();
+		while ! content . is_empty ()
+		{
+			methods . push (content . parse ()?);
+		}
+
+		Ok (Self {brace_token, methods})
+	}
+}
+
+impl ToTokens for InherentMethods
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		self . brace_token . surround
+		(
+			tokens,
+			|tokens| tokens . append_all (&self . methods)
+		);
+	}
+}
+
+impl InherentMethods
+{
+	// Records the public methods of an inherent impl block, along with their
+	// doc comments.
+	pub fn from_impl_items (items: &[ImplItem]) -> Self
+	{
+		let methods = items
+			. iter ()
+			. filter_map
+			(
+				|item| match item
+				{
+					ImplItem::Fn (item_fn)
+						if matches! (item_fn . vis, Visibility::Public (_)) =>
+					{
+						let attrs = item_fn
+							. attrs
+							. iter ()
+							. filter (|attr| attr . path () . is_ident ("doc"))
+							. cloned ()
+							. collect ();
+
+						let mut sig = item_fn . sig . clone ();
+
+						// `mut self` only matters to the body.
+						if let Some (FnArg::Receiver (receiver)) = sig . inputs . first_mut ()
+						{
+							if receiver . reference . is_none ()
+							{
+								receiver . mutability = None;
+							}
+						}
+
+						Some
+						(
+							InherentMethod
+							{
+								attrs,
+								vis: item_fn . vis . clone (),
+								sig,
+								semi_token: Default::default ()
+							}
+						)
+					},
+					_ => None
+				}
+			)
+			. collect ();
+
+		Self {brace_token: Brace::default (), methods}
+	}
+}
+
+// `Path::to::Type::{method, ..}`, selecting inherent methods of a type whose
+// impl block has been made forwardable.  All of its public methods are selected
+// if the braces are left out.
+pub struct ForwardedInherentInfo
+{
+	pub type_path: Path,
+	pub selection: Option <(Token! [::], Brace, Punctuated <Ident, Token! [,]>)>
+}
+
+impl ForwardedInherentInfo
+{
+	pub fn get_macro_path (&self) -> Result <Path>
+	{
+		get_inherent_macro_path (&self . type_path)
+	}
+
+	pub fn select (&self, methods: Vec <InherentMethod>) -> Result <Vec <InherentMethod>>
+	{
+		let Some ((_, _, idents)) = &self . selection else { return Ok (methods); };
+
+		for ident in idents
+		{
+			if ! methods . iter () . any (|method| method . sig . ident == *ident)
+			{
+				return Err
+				(
+					Error::new_spanned
+					(
+						ident,
+						"The impl block has no forwardable method of this name"
+					)
+				);
+			}
+		}
+
+		let methods = methods
+			. into_iter ()
+			. filter (|method| idents . iter () . any (|ident| method . sig . ident == *ident))
+			. collect ();
+
+		Ok (methods)
+	}
+}
+
+impl Parse for ForwardedInherentInfo
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		// `Path` would try to parse the braces as a path segment.
+		let leading_colon = input . parse ()?;
+
+		let mut segments = Punctuated::new ();
+		loop
+		{
+			segments . push_value (input . parse::<PathSegment> ()?);
+
+			if ! input . peek (Token! [::]) || input . peek3 (Brace) { break; }
+
+			segments . push_punct (input . parse ()?);
+		}
+
+		let type_path = Path {leading_colon, segments};
+
+		let selection = if input . peek (Token! [::])
+		{
+			let colon2_token = input . parse ()?;
+
+			let content;
+			let brace_token = braced! (content in input);
+			let idents = Punctuated::parse_terminated (&content)?;
+
+			Some ((colon2_token, brace_token, idents))
+		}
+		else { None };
+
+		Ok (Self {type_path, selection})
+	}
+}
+
+impl ToTokens for ForwardedInherentInfo
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		self . type_path . to_tokens (tokens);
+
+		if let Some ((colon2_token, brace_token, idents)) = &self . selection
+		{
+			colon2_token . to_tokens (tokens);
+			brace_token . surround (tokens, |tokens| idents . to_tokens (tokens));
+		}
+	}
+}
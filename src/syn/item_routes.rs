@@ -0,0 +1,85 @@
+use syn::{Ident, Path, Token, braced};
+use syn::token::Brace;
+use syn::punctuated::Punctuated;
+use syn::parse::{Parse, ParseStream, Result};
+use syn_derive::{Parse, ToTokens};
+use quote::ToTokens;
+
+use crate::type_transformer::base_type_transformer::BaseTransformType;
+
+#[derive (Parse, ToTokens)]
+pub enum ItemRouteTarget
+{
+	#[parse
+	(
+		peek_func = |input| input . peek (Token! [.])
+			|| input . peek (Token! [->])
+			|| input . peek2 (Token! [->])
+	)]
+	Transform (BaseTransformType),
+
+	// `Trait::method`, or just `method` for inherent methods.
+	Method (Path)
+}
+
+#[derive (Parse, ToTokens)]
+pub struct ItemRoute
+{
+	#[parse (Punctuated::parse_separated_nonempty)]
+	pub items: Punctuated <Ident, Token! [,]>,
+	pub fat_arrow_token: Token! [=>],
+	pub target: ItemRouteTarget
+}
+
+// `{item, .. => . member; method => Trait::method; ..}`, routing the named trait
+// items to a different base type transformation than the rest, or forwarding
+// methods to methods of other names.
+#[derive (Default)]
+pub struct ItemRoutes
+{
+	brace_token: Option <Brace>,
+	routes: Punctuated <ItemRoute, Token! [;]>
+}
+
+impl Parse for ItemRoutes
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		if input . peek (Brace)
+		{
+			let content;
+			let brace_token = Some (braced! (content in input));
+			let routes = Punctuated::parse_terminated (&content)?;
+
+			Ok (Self {brace_token, routes})
+		}
+		else
+		{
+			Ok (Self::default ())
+		}
+	}
+}
+
+impl ToTokens for ItemRoutes
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		if self . routes . is_empty () { return; }
+
+		self
+			. brace_token
+			. unwrap_or_default ()
+			. surround (tokens, |tokens| self . routes . to_tokens (tokens));
+	}
+}
+
+impl IntoIterator for ItemRoutes
+{
+	type Item = ItemRoute;
+	type IntoIter = <Punctuated <ItemRoute, Token! [;]> as IntoIterator>::IntoIter;
+
+	fn into_iter (self) -> Self::IntoIter
+	{
+		self . routes . into_iter ()
+	}
+}
@@ -1,8 +1,10 @@
-use syn::{Ident, Index, Fields, Type};
+use syn::{Ident, Index, Field, Fields, Type};
 use syn::{Result, Error};
 use syn_derive::{Parse, ToTokens};
 
-#[derive (Parse, ToTokens)]
+use super::type_def_info::is_pin_attribute;
+
+#[derive (Clone, PartialEq, Parse, ToTokens)]
 pub enum Member
 {
 	#[parse (peek = Ident)]
@@ -14,6 +16,39 @@ pub enum Member
 
 impl Member
 {
+	fn enumerate_members (fields: &Fields)
+	-> impl Iterator <Item = (Member, &Field)>
+	{
+		fields
+			. iter ()
+			. enumerate ()
+			. map
+			(
+				|(i, field)| match &field . ident
+				{
+					Some (ident) => (Member::Ident (ident . clone ()), field),
+					None => (Member::Index (Index::from (i)), field)
+				}
+			)
+	}
+
+	pub fn get_other_members (&self, fields: &Fields) -> Vec <(Member, Type)>
+	{
+		Self::enumerate_members (fields)
+			. filter (|(member, _)| member != self)
+			. map (|(member, field)| (member, field . ty . clone ()))
+			. collect ()
+	}
+
+	pub fn is_pinned (&self, fields: &Fields) -> bool
+	{
+		Self::enumerate_members (fields) . any
+		(
+			|(member, field)| member == *self
+				&& field . attrs . iter () . any (is_pin_attribute)
+		)
+	}
+
 	pub fn get_member_type (&self, fields: &Fields) -> Result <Type>
 	{
 		match fields
@@ -1,4 +1,5 @@
 pub mod member;
+pub mod copy_mode;
 pub mod associated_type;
 pub mod from_type;
 pub mod transformable_types;
@@ -8,10 +9,22 @@ pub mod type_def_info;
 pub mod forwarded_trait_info;
 
 pub mod additional_type_transformers;
+pub mod item_routes;
+pub mod reducers;
+pub mod via_inherent;
+pub mod inherent_methods;
 
 pub mod kw
 {
 	syn::custom_keyword! (Box);
 	syn::custom_keyword! (Option);
 	syn::custom_keyword! (Result);
+
+	syn::custom_keyword! (copy);
+	syn::custom_keyword! (clone);
+
+	syn::custom_keyword! (reduce);
+
+	syn::custom_keyword! (via);
+	syn::custom_keyword! (inherent);
 }
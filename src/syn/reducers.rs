@@ -0,0 +1,126 @@
+use syn::{Ident, Path, Type, ReturnType, Token, braced};
+use syn::token::Brace;
+use syn::punctuated::Punctuated;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::fold::Fold;
+use syn_derive::{Parse, ToTokens};
+use quote::ToTokens;
+
+use super::kw;
+
+#[derive (Parse, ToTokens)]
+pub enum ReducerKey
+{
+	#[parse (peek = Token! [->])]
+	ReturnType {arrow_token: Token! [->], ty: Box <Type>},
+
+	#[parse (peek = Ident)]
+	Method (Ident)
+}
+
+#[derive (Parse, ToTokens)]
+pub struct Reducer
+{
+	key: ReducerKey,
+	fat_arrow_token: Token! [=>],
+	reducer: Path
+}
+
+// `reduce {method => reducer, -> Type => reducer, ..}`, naming how the results
+// of methods forwarded to several delegates are combined, either by method or
+// by return type.
+#[derive (Default)]
+pub struct Reducers
+{
+	reduce_token: Option <kw::reduce>,
+	brace_token: Option <Brace>,
+	reducers: Punctuated <Reducer, Token! [,]>
+}
+
+impl Reducers
+{
+	pub fn fold_types (mut self, folder: &mut impl Fold) -> Self
+	{
+		for reducer in &mut self . reducers
+		{
+			if let ReducerKey::ReturnType {ty, ..} = &mut reducer . key
+			{
+				**ty = folder . fold_type (*ty . clone ());
+			}
+		}
+
+		self
+	}
+
+	// Reducers named for a method take precedence over ones named for its
+	// return type.
+	pub fn get (&self, method: &Ident, output: &ReturnType) -> Option <&Path>
+	{
+		let output_type = match output
+		{
+			ReturnType::Type (_, output_type) =>
+				Some (output_type . to_token_stream () . to_string ()),
+			ReturnType::Default => None
+		};
+
+		self
+			. reducers
+			. iter ()
+			. find
+			(
+				|reducer| matches!
+				(
+					&reducer . key,
+					ReducerKey::Method (ident) if ident == method
+				)
+			)
+			. or_else
+			(
+				|| self . reducers . iter () . find
+				(
+					|reducer| match &reducer . key
+					{
+						ReducerKey::ReturnType {ty, ..} => output_type . as_ref ()
+							== Some (&ty . to_token_stream () . to_string ()),
+						ReducerKey::Method (_) => false
+					}
+				)
+			)
+			. map (|reducer| &reducer . reducer)
+	}
+}
+
+impl Parse for Reducers
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		if input . peek (kw::reduce)
+		{
+			let reduce_token = Some (input . parse ()?);
+
+			let content;
+			let brace_token = Some (braced! (content in input));
+			let reducers = Punctuated::parse_terminated (&content)?;
+
+			Ok (Self {reduce_token, brace_token, reducers})
+		}
+		else
+		{
+			Ok (Self::default ())
+		}
+	}
+}
+
+impl ToTokens for Reducers
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		if self . reduce_token . is_none () { return; }
+
+		self . reduce_token . to_tokens (tokens);
+		self
+			. brace_token
+			. unwrap_or_default ()
+			. surround (tokens, |tokens| self . reducers . to_tokens (tokens));
+	}
+}
@@ -1,4 +1,18 @@
-use syn::{Type, Expr, PathSegment, Token, parse2};
+use syn
+::{
+	Ident,
+	Type,
+	Expr,
+	Path,
+	ReturnType,
+	PathSegment,
+	PathArguments,
+	GenericArgument,
+	TypeParamBound,
+	Token,
+	parse_quote,
+	parse2
+};
 use syn::token::{Paren, Bracket};
 use syn::punctuated::Punctuated;
 use syn_derive::Parse;
@@ -6,22 +20,62 @@ use quote::ToTokens;
 
 use super::kw;
 
-fn last (ty: &Type) -> Option <&PathSegment>
+// The crates that types of the standard library may be named through.
+const STD_CRATES: [&str; 3] = ["std", "core", "alloc"];
+
+// The last segment of a path naming a type of the standard library, either by
+// its bare ident, as it's usually imported, or through one of the given modules,
+// like `std::task::Poll <..>`.
+pub fn std_last <'a> (ty: &'a Type, modules: &[&str]) -> Option <&'a PathSegment>
 {
-	if let Type::Path (type_path) = ty
-	{
-		match type_path . qself
-		{
-			None => type_path . path . segments . last (),
-			Some (_) => None
-		}
-	}
-	else
+	let Type::Path (type_path) = ty else { return None; };
+
+	if type_path . qself . is_some () { return None; }
+
+	let segments: Vec <&PathSegment> =
+		type_path . path . segments . iter () . collect ();
+
+	let is_plain = |segment: &PathSegment, idents: &[&str]|
+		matches! (segment . arguments, PathArguments::None)
+			&& idents . iter () . any (|ident| segment . ident == ident);
+
+	match segments [..]
 	{
-		None
+		[last_segment] if type_path . path . leading_colon . is_none () =>
+			Some (last_segment),
+		[crate_segment, module_segment, last_segment]
+		if is_plain (crate_segment, &STD_CRATES) && is_plain (module_segment, modules) =>
+			Some (last_segment),
+		_ => None
 	}
 }
 
+// The type arguments of a standard library type named `ident`, as long as it
+// has no other arguments.
+pub fn std_type_arguments <'a> (ty: &'a Type, modules: &[&str], ident: &str)
+-> Option <Vec <&'a Type>>
+{
+	let segment = std_last (ty, modules)?;
+
+	if segment . ident != ident { return None; }
+
+	let PathArguments::AngleBracketed (arguments) = &segment . arguments
+	else { return None; };
+
+	arguments
+		. args
+		. iter ()
+		. map
+		(
+			|argument| match argument
+			{
+				GenericArgument::Type (ty) => Some (ty),
+				_ => None
+			}
+		)
+		. collect ()
+}
+
 #[derive (Parse)]
 pub struct BoxType
 {
@@ -35,7 +89,7 @@ impl BoxType
 {
 	pub fn match_type (ty: &Type) -> Option <BoxType>
 	{
-		match last (ty)
+		match std_last (ty, &["boxed"])
 		{
 			Some (path_segment) =>
 				parse2 (path_segment . to_token_stream ()) . ok (),
@@ -57,7 +111,7 @@ impl OptionType
 {
 	pub fn match_type (ty: &Type) -> Option <OptionType>
 	{
-		match last (ty)
+		match std_last (ty, &["option"])
 		{
 			Some (path_segment) =>
 				parse2 (path_segment . to_token_stream ()) . ok (),
@@ -81,7 +135,7 @@ impl ResultType
 {
 	pub fn match_type (ty: &Type) -> Option <ResultType>
 	{
-		match last (ty)
+		match std_last (ty, &["result"])
 		{
 			Some (path_segment) =>
 				parse2 (path_segment . to_token_stream ()) . ok (),
@@ -90,6 +144,135 @@ impl ResultType
 	}
 }
 
+pub struct PollType
+{
+	pub poll_type: Type
+}
+
+impl PollType
+{
+	pub fn match_type (ty: &Type) -> Option <PollType>
+	{
+		match std_type_arguments (ty, &["task"], "Poll") ? [..]
+		{
+			[poll_type] => Some (PollType {poll_type: poll_type . clone ()}),
+			_ => None
+		}
+	}
+}
+
+pub struct ControlFlowType
+{
+	pub break_type: Type,
+
+	// The continue type defaults to `()`, and so may be omitted.
+	pub continue_type: Option <Type>
+}
+
+impl ControlFlowType
+{
+	pub fn match_type (ty: &Type) -> Option <ControlFlowType>
+	{
+		match std_type_arguments (ty, &["ops"], "ControlFlow") ? [..]
+		{
+			[break_type] => Some
+			(
+				ControlFlowType {break_type: break_type . clone (), continue_type: None}
+			),
+			[break_type, continue_type] => Some
+			(
+				ControlFlowType
+				{
+					break_type: break_type . clone (),
+					continue_type: Some (continue_type . clone ())
+				}
+			),
+			_ => None
+		}
+	}
+}
+
+pub struct BoundType
+{
+	pub bound_type: Type
+}
+
+impl BoundType
+{
+	pub fn match_type (ty: &Type) -> Option <BoundType>
+	{
+		match std_type_arguments (ty, &["ops", "collections"], "Bound") ? [..]
+		{
+			[bound_type] => Some (BoundType {bound_type: bound_type . clone ()}),
+			_ => None
+		}
+	}
+}
+
+pub struct PinType
+{
+	pub pinned_type: Type
+}
+
+impl PinType
+{
+	pub fn match_type (ty: &Type) -> Option <PinType>
+	{
+		match std_type_arguments (ty, &["pin"], "Pin") ? [..]
+		{
+			[pinned_type] => Some (PinType {pinned_type: pinned_type . clone ()}),
+			_ => None
+		}
+	}
+}
+
+#[derive (Clone, Copy)]
+pub enum SharedKind
+{
+	Rc,
+	Arc
+}
+
+impl SharedKind
+{
+	pub fn get_path (&self) -> Path
+	{
+		match self
+		{
+			Self::Rc => parse_quote! (std::rc::Rc),
+			Self::Arc => parse_quote! (std::sync::Arc)
+		}
+	}
+}
+
+pub struct SharedType
+{
+	pub kind: SharedKind,
+	pub shared_type: Type
+}
+
+impl SharedType
+{
+	pub fn match_type (ty: &Type) -> Option <SharedType>
+	{
+		let (kind, shared_type) =
+			if let Some (arguments) = std_type_arguments (ty, &["rc"], "Rc")
+			{
+				(SharedKind::Rc, arguments)
+			}
+			else
+			{
+				(SharedKind::Arc, std_type_arguments (ty, &["sync"], "Arc")?)
+			};
+
+		match shared_type [..]
+		{
+			[shared_type] => Some (SharedType {kind, shared_type: shared_type . clone ()}),
+			_ => None
+		}
+	}
+}
+
 #[derive (Parse)]
 pub struct TupleType
 {
@@ -132,3 +315,159 @@ impl ArrayType
 		parse2 (ty . to_token_stream ()) . ok ()
 	}
 }
+
+#[derive (Clone, Copy)]
+pub enum CallbackKind
+{
+	Impl,
+	Ref,
+	RefMut,
+	Box
+}
+
+pub struct CallbackType
+{
+	pub kind: CallbackKind,
+	pub fn_trait: Ident,
+	pub inputs: Punctuated <Type, Token! [,]>,
+	pub output: ReturnType
+}
+
+impl CallbackType
+{
+	fn match_bounds
+	(
+		kind: CallbackKind,
+		bounds: &Punctuated <TypeParamBound, Token! [+]>
+	)
+	-> Option <CallbackType>
+	{
+		bounds . iter () . find_map
+		(
+			|bound|
+			{
+				let TypeParamBound::Trait (trait_bound) = bound
+				else { return None; };
+
+				let segment = trait_bound . path . segments . last ()?;
+
+				let PathArguments::Parenthesized (arguments) = &segment . arguments
+				else { return None; };
+
+				if segment . ident != "Fn"
+					&& segment . ident != "FnMut"
+					&& segment . ident != "FnOnce"
+				{
+					return None;
+				}
+
+				Some
+				(
+					CallbackType
+					{
+						kind,
+						fn_trait: segment . ident . clone (),
+						inputs: arguments . inputs . clone (),
+						output: arguments . output . clone ()
+					}
+				)
+			}
+		)
+	}
+
+	pub fn match_type (ty: &Type) -> Option <CallbackType>
+	{
+		match ty
+		{
+			Type::ImplTrait (type_impl_trait) =>
+				Self::match_bounds (CallbackKind::Impl, &type_impl_trait . bounds),
+			Type::Reference (type_reference) =>
+			{
+				let Type::TraitObject (type_trait_object) = &*type_reference . elem
+				else { return None; };
+
+				let kind = match type_reference . mutability
+				{
+					None => CallbackKind::Ref,
+					Some (_) => CallbackKind::RefMut
+				};
+
+				Self::match_bounds (kind, &type_trait_object . bounds)
+			},
+			_ =>
+			{
+				let Type::TraitObject (type_trait_object) =
+					BoxType::match_type (ty)? . boxed_type
+				else { return None; };
+
+				Self::match_bounds (CallbackKind::Box, &type_trait_object . bounds)
+			}
+		}
+	}
+}
+
+// The traits whose argument-position `impl Trait` types can be adapted to the
+// delegated type, along with the type they're adapted through.
+pub enum ImplTraitBound
+{
+	IntoIterator (Type),
+	Iterator (Type),
+	Into (Type),
+	AsRef (Type)
+}
+
+impl ImplTraitBound
+{
+	pub fn match_bound (bound: &TypeParamBound) -> Option <ImplTraitBound>
+	{
+		let TypeParamBound::Trait (trait_bound) = bound
+		else { return None; };
+
+		let segment = trait_bound . path . segments . last ()?;
+
+		let PathArguments::AngleBracketed (arguments) = &segment . arguments
+		else { return None; };
+
+		let item_type = || arguments . args . iter () . find_map
+		(
+			|argument| match argument
+			{
+				GenericArgument::AssocType (assoc_type)
+				if assoc_type . ident == "Item" => Some (assoc_type . ty . clone ()),
+				_ => None
+			}
+		);
+
+		let type_argument = ||
+		{
+			let mut args = arguments . args . iter ();
+
+			match (args . next (), args . next ())
+			{
+				(Some (GenericArgument::Type (ty)), None) => Some (ty . clone ()),
+				_ => None
+			}
+		};
+
+		if segment . ident == "IntoIterator"
+		{
+			item_type () . map (Self::IntoIterator)
+		}
+		else if segment . ident == "Iterator"
+		{
+			item_type () . map (Self::Iterator)
+		}
+		else if segment . ident == "Into"
+		{
+			type_argument () . map (Self::Into)
+		}
+		else if segment . ident == "AsRef"
+		{
+			type_argument () . map (Self::AsRef)
+		}
+		else
+		{
+			None
+		}
+	}
+}
@@ -1,10 +1,56 @@
-use syn::{Generics, Fields, FieldsNamed, FieldsUnnamed, Token};
+use syn
+::{
+	Attribute,
+	Generics,
+	Fields,
+	FieldsNamed,
+	FieldsUnnamed,
+	Ident,
+	Meta,
+	Token,
+	parse_quote
+};
 use syn::token::{Brace, Paren};
+use syn::punctuated::Punctuated;
 use syn::parse::{Parse, ParseStream, Result};
 use quote::ToTokens;
 
+pub fn is_repr_transparent (attrs: &[Attribute]) -> bool
+{
+	attrs
+		. iter ()
+		. filter (|attr| attr . path () . is_ident ("repr"))
+		. filter_map
+		(
+			|attr| attr
+				. parse_args_with (Punctuated::<Ident, Token! [,]>::parse_terminated)
+				. ok ()
+		)
+		. any (|reprs| reprs . iter () . any (|repr| repr == "transparent"))
+}
+
+pub fn is_repr_packed (attrs: &[Attribute]) -> bool
+{
+	attrs
+		. iter ()
+		. filter (|attr| attr . path () . is_ident ("repr"))
+		. filter_map
+		(
+			|attr| attr
+				. parse_args_with (Punctuated::<Meta, Token! [,]>::parse_terminated)
+				. ok ()
+		)
+		. any (|reprs| reprs . iter () . any (|repr| repr . path () . is_ident ("packed")))
+}
+
+pub fn is_pin_attribute (attr: &Attribute) -> bool
+{
+	attr . path () . is_ident ("pin")
+}
+
 pub struct TypeDefInfo
 {
+	pub repr_transparent: bool,
 	pub type_token: Token! [type],
 	pub generics: Generics,
 	pub fields: Fields
@@ -14,6 +60,11 @@ impl Parse for TypeDefInfo
 {
 	fn parse (input: ParseStream <'_>) -> Result <Self>
 	{
+		let repr_transparent = is_repr_transparent
+		(
+			&input . call (Attribute::parse_outer)?
+		);
+
 		let type_token = input . parse ()?;
 
 		let mut generics: Generics = input . parse ()?;
@@ -41,7 +92,7 @@ impl Parse for TypeDefInfo
 			return Err (lookahead . error ())
 		};
 
-		Ok (Self {type_token, generics, fields})
+		Ok (Self {repr_transparent, type_token, generics, fields})
 	}
 }
 
@@ -49,6 +100,12 @@ impl ToTokens for TypeDefInfo
 {
 	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
 	{
+		if self . repr_transparent
+		{
+			let repr_attribute: Attribute = parse_quote! (#[repr (transparent)]);
+			repr_attribute . to_tokens (tokens);
+		}
+
 		self . type_token . to_tokens (tokens);
 		self . generics . to_tokens (tokens);
 		self . generics . where_clause . to_tokens (tokens);
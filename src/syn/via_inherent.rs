@@ -0,0 +1,48 @@
+use syn::parse::{Parse, ParseStream, Result};
+use syn_derive::{Parse, ToTokens};
+use quote::ToTokens;
+
+use super::kw;
+
+#[derive (Parse, ToTokens)]
+struct ViaInherentTokens
+{
+	via_token: kw::via,
+	inherent_token: kw::inherent
+}
+
+// `via inherent`, forwarding the trait's methods to the inherent methods of the
+// same names on the delegated type.
+#[derive (Default)]
+pub struct ViaInherent
+{
+	tokens: Option <ViaInherentTokens>
+}
+
+impl ViaInherent
+{
+	pub fn is_set (&self) -> bool
+	{
+		self . tokens . is_some ()
+	}
+}
+
+impl Parse for ViaInherent
+{
+	fn parse (input: ParseStream) -> Result <Self>
+	{
+		let tokens =
+			if input . peek (kw::via) { Some (input . parse ()?) }
+			else { None };
+
+		Ok (Self {tokens})
+	}
+}
+
+impl ToTokens for ViaInherent
+{
+	fn to_tokens (&self, tokens: &mut proc_macro2::TokenStream)
+	{
+		self . tokens . to_tokens (tokens);
+	}
+}
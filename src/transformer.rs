@@ -9,26 +9,46 @@ use syn
 	Receiver,
 	PatType,
 	ReturnType,
+	TypeBareFn,
+	TypeImplTrait,
+	TypeReference,
+	Stmt,
+	Generics,
+	GenericParam,
+	GenericArgument,
+	PathArguments,
+	TypeParamBound,
+	TraitBound,
+	TraitBoundModifier,
 	TraitItem,
 	TraitItemConst,
 	TraitItemFn,
 	TraitItemType,
+	BoundLifetimes,
 	ImplItem,
 	ImplItemConst,
 	ImplItemFn,
 	ImplItemType,
 	WherePredicate,
+	WhereClause,
 	Index,
 	Token,
 	parse_quote,
-	parse2
+	parse_quote_spanned
 };
 use syn::punctuated::Punctuated;
 use syn::parse::{Result, Error};
 use syn::fold::Fold;
-use quote::ToTokens;
+use syn::spanned::Spanned;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, format_ident, ToTokens};
+
+use crate::generics::{add_associated_type_bindings, get_param_idents};
 
 use crate::syn::transformable_types::*;
+use crate::syn::associated_type::{AssociatedType, QualifiedAssociatedType};
+
+use crate::fold::type_transformer::TypeTransformer;
 
 use crate::value_transformer::value_transformer::ValueTransformer;
 
@@ -39,6 +59,121 @@ use crate::type_transformer
 	additional_type_transformer::*
 };
 
+// Trait method signatures may bind their arguments with arbitrary patterns, so
+// each argument is given a fresh name that the delegated call can refer to.
+pub fn rename_args (inputs: Punctuated <FnArg, Token! [,]>)
+-> Punctuated <FnArg, Token! [,]>
+{
+	inputs
+		. into_iter ()
+		. enumerate ()
+		. map
+		(
+			|(i, input)| match input
+			{
+				FnArg::Typed (pat_type) =>
+				{
+					let arg_ident =
+						format_ident! ("arg_{}", i, span = Span::mixed_site ());
+
+					FnArg::Typed
+					(
+						PatType {pat: parse_quote! (#arg_ident), ..pat_type}
+					)
+				},
+				receiver => receiver
+			}
+		)
+		. collect ()
+}
+
+pub fn mentions_any (tokens: TokenStream, idents: &[Ident]) -> bool
+{
+	tokens . into_iter () . any
+	(
+		|token| match token
+		{
+			TokenTree::Ident (ident) => idents . contains (&ident),
+			TokenTree::Group (group) => mentions_any (group . stream (), idents),
+			_ => false
+		}
+	)
+}
+
+fn respan (tokens: TokenStream, span: Span) -> TokenStream
+{
+	tokens
+		. into_iter ()
+		. map
+		(
+			|mut token|
+			{
+				if let TokenTree::Group (group) = &token
+				{
+					let mut respanned_group = proc_macro2::Group::new
+					(
+						group . delimiter (),
+						respan (group . stream (), span)
+					);
+					respanned_group . set_span (span);
+					token = TokenTree::Group (respanned_group);
+				}
+				else
+				{
+					token . set_span (span);
+				}
+
+				token
+			}
+		)
+		. collect ()
+}
+
+// Matches `Into <X>` or `From <X>` bounds, returning the trait's name and `X`.
+fn match_conversion_bound (bound: &TypeParamBound) -> Option <(Ident, Type)>
+{
+	let TypeParamBound::Trait (TraitBound {path, lifetimes: None, ..}) = bound
+	else { return None; };
+
+	let segment = path . segments . last ()?;
+
+	if segment . ident != "Into" && segment . ident != "From" { return None; }
+
+	let PathArguments::AngleBracketed (arguments) = &segment . arguments
+	else { return None; };
+
+	match arguments . args . iter () . collect::<Vec <_>> () . as_slice ()
+	{
+		[GenericArgument::Type (ty)] => Some ((segment . ident . clone (), ty . clone ())),
+		_ => None
+	}
+}
+
+// A method generic parameter bounded by `Into <Self>` is instantiated with the
+// delegated type in the delegated call, and its values are converted through
+// `Self`.  One bounded by `From <Self>` is instantiated the same way, and its
+// values are converted back through `Self`.
+struct ConvertedParam
+{
+	ident: Ident,
+	via_type: Type,
+	delegated_type: Type,
+	is_input: bool
+}
+
+impl ConvertedParam
+{
+	fn matches (&self, ty: &Type) -> bool
+	{
+		match ty
+		{
+			Type::Path (type_path) if type_path . qself . is_none () =>
+				type_path . path . get_ident () == Some (&self . ident),
+			_ => false
+		}
+	}
+}
+
 pub struct TransformerBuilder
 {
 	associated_type_transformers: AssociatedTypeTransformers,
@@ -82,21 +217,39 @@ impl TransformerBuilder
 		}
 	}
 
-	pub fn into_transformer (self, delegated_type: Type, forwarded_trait: Path)
+	pub fn into_transformer
+	(
+		self,
+		delegated_type: Type,
+		forwarded_trait: Option <Path>
+	)
 	-> Transformer
 	{
 		let Self {associated_type_transformers, independent_type_transformers} = self;
 
-		let forwarded_trait = independent_type_transformers
-			. get_type_transformer ()
-			. fold_path (forwarded_trait);
+		let forwarded_trait = forwarded_trait . map
+		(
+			|forwarded_trait| independent_type_transformers
+				. get_type_transformer ()
+				. fold_path (forwarded_trait)
+		);
 
 		Transformer
 		{
 			associated_type_transformers,
 			independent_type_transformers,
 			delegated_type,
-			forwarded_trait
+			forwarded_trait,
+			is_argument: false,
+			is_const: false,
+			in_callback: false,
+			copy_ins: Vec::new (),
+			copy_outs: Vec::new (),
+			converted_params: Vec::new (),
+			shares_args: false,
+			bridge_predicates: Punctuated::new (),
+			method_predicates: Punctuated::new (),
+			impl_params: Vec::new ()
 		}
 	}
 }
@@ -106,23 +259,141 @@ pub struct Transformer
 	associated_type_transformers: AssociatedTypeTransformers,
 	independent_type_transformers: IndependentTypeTransformers,
 	delegated_type: Type,
-	forwarded_trait: Path
+
+	// Inherent methods aren't forwarded through any trait.
+	forwarded_trait: Option <Path>,
+
+	// Set while transforming a whole argument, as opposed to some value nested
+	// inside of one.
+	is_argument: bool,
+
+	// Set while transforming the value of an associated constant, where only
+	// constant expressions can be used.
+	is_const: bool,
+
+	// Set while transforming the arguments and return value of a callback,
+	// where borrowed forms of the receiver can be rebuilt, and where forms that
+	// can't be transformed are rejected rather than passed along.
+	in_callback: bool,
+
+	// Copied values are bound before the delegated call and written back into
+	// the borrowed receiver after it.
+	copy_ins: Vec <Stmt>,
+	copy_outs: Vec <Stmt>,
+
+	// Generic parameters of the method being forwarded that are converted
+	// through `Self`.
+	converted_params: Vec <ConvertedParam>,
+
+	// Set while building one of several delegated calls that the arguments of
+	// a method are passed along to.
+	shares_args: bool,
+
+	// Method bounds on `Self` have to hold for the delegated type for the
+	// delegated call to be made.
+	bridge_predicates: Punctuated <WherePredicate, Token! [,]>,
+
+	// Method bounds on `Self`, restated for the delegated type, which the
+	// forwarded method has to be given in turn.
+	method_predicates: Punctuated <WherePredicate, Token! [,]>,
+
+	// Generic parameters of the trait implementation, which bounds on single
+	// methods can't restrict.
+	impl_params: Vec <Ident>
 }
 
 impl Transformer
 {
-	pub fn get_transformed_forwarded_trait (&self) -> &Path
+	pub fn get_transformed_forwarded_trait (&self) -> Option <&Path>
+	{
+		self . forwarded_trait . as_ref ()
+	}
+
+	// Trait items that aren't forwarded to other methods are forwarded through
+	// the forwarded trait, which inherent methods never are.
+	fn get_forwarded_trait (&self) -> Result <&Path>
+	{
+		self . forwarded_trait . as_ref () . ok_or_else
+		(
+			|| Error::new (Span::call_site (), "Only trait items can be forwarded through a trait")
+		)
+	}
+
+	pub fn get_delegated_type (&self) -> &Type
+	{
+		&self . delegated_type
+	}
+
+	pub fn set_impl_params (&mut self, impl_params: Vec <Ident>)
 	{
-		&self . forwarded_trait
+		self . impl_params = impl_params;
+	}
+
+	pub fn take_method_predicates (&mut self)
+	-> Punctuated <WherePredicate, Token! [,]>
+	{
+		std::mem::take (&mut self . method_predicates)
+	}
+
+	pub fn transforms_associated_type (&self, ident: &Ident) -> bool
+	{
+		self . associated_type_transformers . get (ident) . is_some ()
 	}
 
 	fn get_transformer_for_type <'a, 'b> (&'a mut self, ty: &'b Type)
 	-> Option <(&'b Type, Type, &'a mut ValueTransformer)>
 	{
-		self
-			. associated_type_transformers
-			. get_transformation (ty, &self . delegated_type, &self . forwarded_trait)
-			. or (self . independent_type_transformers . get_transformation (ty))
+		let Self
+		{
+			associated_type_transformers,
+			independent_type_transformers,
+			delegated_type,
+			forwarded_trait,
+			..
+		}
+			= self;
+
+		forwarded_trait
+			. as_ref ()
+			. and_then
+			(
+				|forwarded_trait| associated_type_transformers
+					. get_transformation (ty, delegated_type, forwarded_trait)
+			)
+			. or (independent_type_transformers . get_transformation (ty))
+	}
+
+	// Maps the receiver's forms of transformed types to the delegated type's
+	// forms, as they appear in the delegated trait implementation.
+	fn get_delegated_type_transformer (&self)
+	-> TypeTransformer <impl FnMut (&Type) -> Option <Type> + '_>
+	{
+		let Self
+		{
+			associated_type_transformers,
+			independent_type_transformers,
+			delegated_type,
+			forwarded_trait,
+			..
+		}
+			= self;
+
+		TypeTransformer::new
+		(
+			|ty| forwarded_trait
+				. as_ref ()
+				. and_then
+				(
+					|forwarded_trait| associated_type_transformers
+						. get_delegated_type (ty, delegated_type, forwarded_trait)
+				)
+				. or_else (|| independent_type_transformers . get_to_type (ty))
+		)
+	}
+
+	fn fold_delegated_type (&self, ty: Type) -> Type
+	{
+		self . get_delegated_type_transformer () . fold_type (ty)
 	}
 
 	fn get_transformer_for_ref_type <'a, 'b> (&'a mut self, ty: &'b Type)
@@ -191,7 +462,36 @@ impl Transformer
 		Ok ((input, false))
 	}
 
-	fn transform_input_result (&mut self, input: Expr, inner_type: &Type)
+	fn transform_input_result
+	(
+		&mut self,
+		input: Expr,
+		ok_type: &Type,
+		err_type: &Type
+	)
+	-> Result <(Expr, bool)>
+	{
+		let (ok_input, ok_transformed) =
+			self . transform_input (parse_quote! (v), ok_type)?;
+		let (err_input, err_transformed) =
+			self . transform_input (parse_quote! (e), err_type)?;
+
+		let mut input = input;
+
+		if ok_transformed
+		{
+			input = parse_quote! (#input . map (|v| #ok_input));
+		}
+
+		if err_transformed
+		{
+			input = parse_quote! (#input . map_err (|e| #err_input));
+		}
+
+		Ok ((input, ok_transformed || err_transformed))
+	}
+
+	fn transform_input_poll (&mut self, input: Expr, inner_type: &Type)
 	-> Result <(Expr, bool)>
 	{
 		let inner_input = parse_quote! (v);
@@ -210,51 +510,44 @@ impl Transformer
 		Ok ((input, false))
 	}
 
-	fn transform_input_tuple
+	fn transform_input_control_flow
 	(
 		&mut self,
 		input: Expr,
-		inner_types: &Punctuated <Type, Token! [,]>
+		break_type: &Type,
+		continue_type: Option <&Type>
 	)
 	-> Result <(Expr, bool)>
 	{
-		let mut any_transformed: bool = false;
-
-		let expr_var: Ident = parse_quote! (v);
-		let mut inner_inputs = Punctuated::<Expr, Token! [,]>::new ();
-
-		for i in 0..(inner_types . len ())
+		let (break_input, break_transformed) =
+			self . transform_input (parse_quote! (v), break_type)?;
+		let (continue_input, continue_transformed) = match continue_type
 		{
-			let idx = Index::from (i);
-			let inner_input = parse_quote! (#expr_var . #idx);
-			let inner_type = &inner_types [i];
-			let (inner_input, input_transformed) =
-				self . transform_input (inner_input, inner_type)?;
-
-			inner_inputs . push (inner_input);
-			any_transformed |= input_transformed;
-		}
+			Some (continue_type) =>
+				self . transform_input (parse_quote! (v), continue_type)?,
+			None => (parse_quote! (v), false)
+		};
 
-		if any_transformed
+		if break_transformed || continue_transformed
 		{
 			let input = parse_quote!
 			(
+				match #input
 				{
-					let #expr_var = #input;
-
-					(#inner_inputs)
+					::core::ops::ControlFlow::Continue (v) =>
+						::core::ops::ControlFlow::Continue (#continue_input),
+					::core::ops::ControlFlow::Break (v) =>
+						::core::ops::ControlFlow::Break (#break_input)
 				}
 			);
 
-			Ok ((input, true))
-		}
-		else
-		{
-			Ok ((input, false))
+			return Ok ((input, true));
 		}
+
+		Ok ((input, false))
 	}
 
-	fn transform_input_array (&mut self, input: Expr, inner_type: &Type)
+	fn transform_input_bound (&mut self, input: Expr, inner_type: &Type)
 	-> Result <(Expr, bool)>
 	{
 		let inner_input = parse_quote! (v);
@@ -264,155 +557,440 @@ impl Transformer
 		{
 			let input = parse_quote!
 			(
-				#input . map (|v| #inner_input)
+				match #input
+				{
+					::core::ops::Bound::Included (v) =>
+						::core::ops::Bound::Included (#inner_input),
+					::core::ops::Bound::Excluded (v) =>
+						::core::ops::Bound::Excluded (#inner_input),
+					::core::ops::Bound::Unbounded => ::core::ops::Bound::Unbounded
+				}
 			);
 
-			return Ok ((input, true))
+			return Ok ((input, true));
 		}
 
 		Ok ((input, false))
 	}
 
-	fn transform_input
-	(
-		&mut self,
-		input: Expr,
-		input_type: &Type
-	)
+	fn transform_input_callback (&mut self, input: Expr, callback: CallbackType)
 	-> Result <(Expr, bool)>
 	{
-		if let Some ((from_type, to_type, value_transformer)) =
-			self . get_transformer_for_type (input_type)
+		let CallbackType {kind, fn_trait, inputs, output} = callback;
+
+		let mut any_transformed: bool = false;
+
+		let mut param_idents = Vec::<Ident>::new ();
+		let mut param_types = Vec::<Type>::new ();
+		let mut args = Punctuated::<Expr, Token! [,]>::new ();
+
+		let in_callback = std::mem::replace (&mut self . in_callback, true);
+
+		// The callback is called by the delegated implementation, so its
+		// arguments are transformed from the delegated type back into the
+		// receiver type, and its return value is transformed the other way.
+		for (i, input_type) in inputs . iter () . enumerate ()
 		{
-			return Ok
-			((
-				value_transformer . transform_input
-				(
-					input,
-					from_type,
-					&to_type
-				)?,
-				true
-			));
+			let param_ident =
+				format_ident! ("callback_arg_{}", i, span = Span::mixed_site ());
+			let (arg, arg_transformed) =
+				self . transform_output (parse_quote! (#param_ident), input_type)?;
+
+			param_types . push (self . fold_delegated_type (input_type . clone ()));
+			param_idents . push (param_ident);
+			args . push (arg);
+			any_transformed |= arg_transformed;
 		}
-		else if let Some ((from_type, to_type, value_transformer)) =
-			self . get_transformer_for_ref_type (input_type)
+
+		let needs_mut_binding = fn_trait == "FnMut"
+			&& matches! (kind, CallbackKind::Impl | CallbackKind::Box);
+
+		let callee: Expr = if needs_mut_binding
 		{
-			return Ok
-			((
-				value_transformer . transform_input_ref
-				(
-					input,
-					from_type,
-					&to_type
-				)?,
-				true
-			));
+			let callee_ident = Ident::new ("callback", Span::mixed_site ());
+			parse_quote! (#callee_ident)
 		}
-		else if let Some ((from_type, to_type, value_transformer)) =
-			self . get_transformer_for_ref_mut_type (input_type)
+		else
 		{
-			return Ok
-			((
-				value_transformer . transform_input_ref_mut
-				(
-					input,
-					from_type,
-					&to_type
-				)?,
-				true
-			));
-		}
-		else if let Some (BoxType {boxed_type, ..}) =
-			BoxType::match_type (input_type)
+			parse_quote! ((#input))
+		};
+
+		let call_expr = parse_quote! (#callee (#args));
+
+		let body_expr = if let ReturnType::Type (_, boxed_ty) = &output
 		{
-			return self . transform_input_box (input, &boxed_type);
+			let (body_expr, output_transformed) =
+				self . transform_input (call_expr, boxed_ty . as_ref ())?;
+
+			any_transformed |= output_transformed;
+
+			body_expr
 		}
-		else if let Some (OptionType {option_type, ..}) =
-			OptionType::match_type (input_type)
+		else
 		{
-			return self . transform_input_option (input, &option_type);
-		}
-		else if let Some (ResultType {result_type, ..}) =
-			ResultType::match_type (input_type)
+			call_expr
+		};
+
+		self . in_callback = in_callback;
+
+		if ! any_transformed
 		{
-			return self . transform_input_result (input, &result_type);
+			return Ok ((input, false));
 		}
-		else if let Some (TupleType {types, ..}) =
-			TupleType::match_type (input_type)
+
+		let closure: Expr = parse_quote!
+		(
+			move |#(#param_idents: #param_types),*| #body_expr
+		);
+
+		let wrapped_closure: Expr = match kind
 		{
-			return self . transform_input_tuple (input, &types);
-		}
-		else if let Some (ArrayType {ty, ..}) =
-			ArrayType::match_type (input_type)
+			CallbackKind::Impl => closure,
+			CallbackKind::Ref => parse_quote! (&#closure),
+			CallbackKind::RefMut => parse_quote! (&mut #closure),
+			CallbackKind::Box => parse_quote! (std::boxed::Box::new (#closure))
+		};
+
+		let input = if needs_mut_binding
 		{
-			return self . transform_input_array (input, &ty);
+			parse_quote!
+			(
+				{
+					let mut #callee = #input;
+
+					#wrapped_closure
+				}
+			)
 		}
+		else
+		{
+			wrapped_closure
+		};
 
-		Ok ((input, false))
+		Ok ((input, true))
 	}
 
-	fn transform_output_box (&mut self, output: Expr, inner_type: &Type)
-	-> Result <(Expr, bool)>
+	fn check_transparent_type (&mut self, ty: &Type) -> Result <()>
 	{
-		let inner_output = parse_quote! ((*#output));
-
-		if let (inner_output, true) =
-			self . transform_output (inner_output, inner_type)?
+		if let Some (is_transparent) = self
+			. get_transformer_for_type (ty)
+			. map (|(_, _, value_transformer)| value_transformer . is_transparent ())
 		{
-			let output = parse_quote!
-			(
-				Box::new (#inner_output)
-			);
+			return if is_transparent { Ok (()) }
+			else
+			{
+				Err
+				(
+					Error::new_spanned
+					(
+						ty,
+						"Function pointers can only be forwarded if the types they transform belong to a `#[repr (transparent)]` receiver"
+					)
+				)
+			};
+		}
 
-			return Ok ((output, true));
+		if let Type::Reference (type_reference) = ty
+		{
+			self . check_transparent_type (&type_reference . elem)
+		}
+		else if let Type::Ptr (type_ptr) = ty
+		{
+			self . check_transparent_type (&type_ptr . elem)
+		}
+		else if let Some (BoxType {boxed_type, ..}) = BoxType::match_type (ty)
+		{
+			self . check_transparent_type (&boxed_type)
+		}
+		else if let Some (SharedType {shared_type, ..}) =
+			SharedType::match_type (ty)
+		{
+			self . check_transparent_type (&shared_type)
+		}
+		else if self . fold_delegated_type (ty . clone ()) == *ty
+		{
+			Ok (())
+		}
+		else
+		{
+			Err
+			(
+				Error::new_spanned
+				(
+					ty,
+					"Function pointers can only be forwarded if transformed types appear directly or behind a pointer in their signatures"
+				)
+			)
 		}
+	}
 
-		Ok ((output, false))
+	// Shared pointers can't be mapped over, so they're only transformed if they
+	// point directly at a transformed type.
+	fn check_shared_type (&self, shared_type: &Type) -> Result <()>
+	{
+		if self . fold_delegated_type (shared_type . clone ()) == *shared_type
+		{
+			Ok (())
+		}
+		else
+		{
+			Err
+			(
+				Error::new_spanned
+				(
+					shared_type,
+					"Shared pointers can only be forwarded if they point directly to a transformed type"
+				)
+			)
+		}
 	}
 
-	fn transform_output_option (&mut self, output: Expr, inner_type: &Type)
+	// Argument-position `impl Trait` types are adapted by wrapping the argument
+	// in a type that implements the same trait for the delegated type.
+	fn transform_input_impl_trait
+	(
+		&mut self,
+		input: Expr,
+		impl_trait: &TypeImplTrait
+	)
 	-> Result <(Expr, bool)>
 	{
-		let inner_output = parse_quote! (v);
+		let mut transformed_bounds = impl_trait
+			. bounds
+			. iter ()
+			. filter
+			(
+				|bound| self
+					. get_delegated_type_transformer ()
+					. fold_type_param_bound ((*bound) . clone ())
+					!= **bound
+			);
 
-		if let (inner_output, true) =
-			self . transform_output (inner_output, inner_type)?
+		let Some (bound) = transformed_bounds . next () else
 		{
-			let output = parse_quote!
+			return Ok ((input, false));
+		};
+
+		if let Some (other_bound) = transformed_bounds . next ()
+		{
+			return Err
 			(
-				#output . map (|v| #inner_output)
+				Error::new_spanned
+				(
+					other_bound,
+					"Argument-position `impl Trait` types can only mention transformed types in one bound"
+				)
 			);
+		}
 
-			return Ok ((output, true));
+		let adapter = ImplTraitBound::match_bound (bound);
+
+		// A mapped iterator carries the bounds of the iterator it maps over,
+		// but the other adapters are of types of their own, so the argument's
+		// other bounds wouldn't necessarily hold for them.
+		if ! matches! (adapter, None | Some (ImplTraitBound::Iterator (_)))
+		{
+			if let Some (other_bound) = impl_trait
+				. bounds
+				. iter ()
+				. find (|other_bound| *other_bound != bound)
+			{
+				return Err
+				(
+					Error::new_spanned
+					(
+						other_bound,
+						"Argument-position `impl Trait` types adapted through `IntoIterator`, `Into` or `AsRef` can't have other bounds"
+					)
+				);
+			}
 		}
 
-		Ok ((output, false))
+		let item_ident = Ident::new ("item", Span::mixed_site ());
+
+		let input = match adapter
+		{
+			Some (ImplTraitBound::IntoIterator (item_type)) =>
+			{
+				let (item, _) =
+					self . transform_input (parse_quote! (#item_ident), &item_type)?;
+
+				parse_quote!
+				(
+					std::iter::Iterator::map
+					(
+						std::iter::IntoIterator::into_iter (#input),
+						|#item_ident| #item
+					)
+				)
+			},
+			Some (ImplTraitBound::Iterator (item_type)) =>
+			{
+				let (item, _) =
+					self . transform_input (parse_quote! (#item_ident), &item_type)?;
+
+				parse_quote!
+				(
+					std::iter::Iterator::map (#input, |#item_ident| #item)
+				)
+			},
+			Some (ImplTraitBound::Into (into_type)) =>
+			{
+				let input = parse_quote!
+				(
+					std::convert::Into::<#into_type>::into (#input)
+				);
+
+				self . transform_input (input, &into_type)? . 0
+			},
+			Some (ImplTraitBound::AsRef (as_ref_type)) =>
+			{
+				// There's no `AsRef` implementation for plain references, so the
+				// borrow is passed along as a `Cow` instead.
+				let delegated_type = self . fold_delegated_type (as_ref_type . clone ());
+				self . add_bridge_predicate
+				(
+					parse_quote! (#delegated_type: std::borrow::ToOwned)
+				);
+
+				let input = parse_quote!
+				(
+					std::convert::AsRef::<#as_ref_type>::as_ref (&#input)
+				);
+				let (input, _) =
+					self . transform_input (input, &parse_quote! (&#as_ref_type))?;
+
+				parse_quote! (std::borrow::Cow::<#delegated_type>::Borrowed (#input))
+			},
+			None => return Err
+			(
+				Error::new_spanned
+				(
+					bound,
+					"Argument-position `impl Trait` types can only mention transformed types through `IntoIterator`, `Iterator`, `Into` or `AsRef`"
+				)
+			)
+		};
+
+		Ok ((input, true))
 	}
 
-	fn transform_output_result (&mut self, output: Expr, inner_type: &Type)
-	-> Result <(Expr, bool)>
+	// Borrowed shared pointers would have to be reinterpreted as pointers to the
+	// delegated type, which isn't guaranteed to be sound even for transparent
+	// receivers.
+	fn check_borrowed_shared_type (&self, ty: &Type) -> Result <()>
 	{
-		let inner_output = parse_quote! (v);
+		if let Type::Reference (type_reference) = ty
+		{
+			if let Some (SharedType {shared_type, ..}) =
+				SharedType::match_type (&type_reference . elem)
+			{
+				if self . fold_delegated_type (shared_type . clone ()) != shared_type
+				{
+					return Err
+					(
+						Error::new_spanned
+						(
+							ty,
+							"Borrowed shared pointers cannot be forwarded, take the pointer by value instead"
+						)
+					);
+				}
+			}
+		}
 
-		if let (inner_output, true) =
-			self . transform_output (inner_output, inner_type)?
+		Ok (())
+	}
+
+	fn check_pinned_type (&self, ty: &Type, pinned_type: &Type) -> Result <()>
+	{
+		if self . fold_delegated_type (pinned_type . clone ()) != *pinned_type
 		{
-			let output = parse_quote!
+			return Err
 			(
-				#output . map (|v| #inner_output)
+				Error::new_spanned
+				(
+					ty,
+					"Unsupported pinned receiver, only `Pin <&Self>` and `Pin <&mut Self>` arguments can be forwarded"
+				)
 			);
+		}
 
-			return Ok ((output, true));
+		Ok (())
+	}
+
+	// Borrowed forms of the receiver are only rebuilt for the arguments of
+	// callbacks, which the delegated implementation only lends to them.
+	fn check_borrowed_output (in_callback: bool, ty: &Type) -> Result <()>
+	{
+		if in_callback { return Ok (()); }
+
+		Err
+		(
+			Error::new_spanned
+			(
+				ty,
+				"Borrowed return values cannot be transformed for forwarding"
+			)
+		)
+	}
+
+	fn check_callback_type (&self, ty: &Type) -> Result <()>
+	{
+		if self . in_callback && self . fold_delegated_type (ty . clone ()) != *ty
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					ty,
+					"Callback arguments and return values can only be transformed in the forms that method arguments and return values are"
+				)
+			);
 		}
 
-		Ok ((output, false))
+		Ok (())
 	}
 
-	fn transform_output_tuple
+	fn transform_input_fn_pointer (&mut self, input: Expr, bare_fn: &TypeBareFn)
+	-> Result <(Expr, bool)>
+	{
+		let fn_type = Type::BareFn (bare_fn . clone ());
+		let delegated_fn_type = self . fold_delegated_type (fn_type . clone ());
+
+		if delegated_fn_type == fn_type
+		{
+			return Ok ((input, false));
+		}
+
+		for input_type in bare_fn . inputs . iter () . map (|arg| &arg . ty)
+		{
+			self . check_transparent_type (input_type)?;
+		}
+
+		if let ReturnType::Type (_, output_type) = &bare_fn . output
+		{
+			self . check_transparent_type (output_type)?;
+		}
+
+		// A closure that captures the function pointer cannot be turned back
+		// into a function pointer, but a `#[repr (transparent)]` receiver is
+		// ABI-compatible with the type that it wraps.
+		let input = parse_quote!
+		(
+			unsafe
+			{
+				std::mem::transmute::<#fn_type, #delegated_fn_type> (#input)
+			}
+		);
+
+		Ok ((input, true))
+	}
+
+	fn transform_input_tuple
 	(
 		&mut self,
-		output: Expr,
+		input: Expr,
 		inner_types: &Punctuated <Type, Token! [,]>
 	)
 	-> Result <(Expr, bool)>
@@ -420,223 +998,1385 @@ impl Transformer
 		let mut any_transformed: bool = false;
 
 		let expr_var: Ident = parse_quote! (v);
-		let mut inner_outputs = Punctuated::<Expr, Token! [,]>::new ();
+		let mut inner_inputs = Punctuated::<Expr, Token! [,]>::new ();
 
 		for i in 0..(inner_types . len ())
 		{
 			let idx = Index::from (i);
-			let inner_output = parse_quote! (#expr_var . #idx);
+			let inner_input = parse_quote! (#expr_var . #idx);
 			let inner_type = &inner_types [i];
-			let (inner_output, output_transformed) =
-				self . transform_output (inner_output, inner_type)?;
+			let (inner_input, input_transformed) =
+				self . transform_input (inner_input, inner_type)?;
 
-			inner_outputs . push (inner_output);
-			any_transformed |= output_transformed;
+			inner_inputs . push (inner_input);
+			any_transformed |= input_transformed;
 		}
 
 		if any_transformed
 		{
-			let output = parse_quote!
+			let input = parse_quote!
 			(
 				{
-					let #expr_var = #output;
+					let #expr_var = #input;
 
-					(#inner_outputs)
+					(#inner_inputs)
 				}
 			);
 
-			Ok ((output, true))
+			Ok ((input, true))
 		}
 		else
 		{
-			Ok ((output, false))
+			Ok ((input, false))
 		}
 	}
 
-	fn transform_output_array (&mut self, output: Expr, inner_type: &Type)
+	fn transform_input_array (&mut self, input: Expr, inner_type: &Type)
 	-> Result <(Expr, bool)>
 	{
-		let inner_output = parse_quote! (v);
+		let inner_input = parse_quote! (v);
 
-		if let (inner_output, true) =
-			self . transform_output (inner_output, inner_type)?
+		if let (inner_input, true) =
+			self . transform_input (inner_input, inner_type)?
 		{
-			let output = parse_quote!
+			let input = parse_quote!
 			(
-				#output . map (|v| #inner_output)
+				#input . map (|v| #inner_input)
 			);
 
-			return Ok ((output, true))
+			return Ok ((input, true))
+		}
+
+		Ok ((input, false))
+	}
+
+	fn transform_input_copied_ref_mut
+	(
+		&mut self,
+		input: Expr,
+		input_type: &Type,
+		is_argument: bool
+	)
+	-> Result <Option <Expr>>
+	{
+		let copied_ident = format_ident!
+		(
+			"copied_{}",
+			self . copy_ins . len (),
+			span = Span::mixed_site ()
+		);
+
+		let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_mut_type (input_type)
+		else { return Ok (None); };
+
+		if ! value_transformer . copies () { return Ok (None); }
+
+		if ! is_argument
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					input_type,
+					"Copied mutable borrows can only be forwarded as whole arguments"
+				)
+			);
+		}
+
+		let copy_in = value_transformer
+			. transform_input_copy_in (input . clone (), from_type, &to_type)?;
+		let copy_out = value_transformer
+			. transform_output (parse_quote! (#copied_ident), from_type, &to_type)?;
+
+		self . copy_ins . push (parse_quote! (let mut #copied_ident = #copy_in;));
+		self . copy_outs . push (parse_quote! (*#input = #copy_out;));
+
+		Ok (Some (parse_quote! (&mut #copied_ident)))
+	}
+
+	fn transform_input
+	(
+		&mut self,
+		input: Expr,
+		input_type: &Type
+	)
+	-> Result <(Expr, bool)>
+	{
+		let is_argument = std::mem::replace (&mut self . is_argument, false);
+
+		if let Some (converted_input) =
+			self . transform_input_converted_param (input . clone (), input_type)?
+		{
+			return Ok ((converted_input, true));
+		}
+		else if let Some (copied_input) = self . transform_input_copied_ref_mut
+		(
+			input . clone (),
+			input_type,
+			is_argument
+		)?
+		{
+			return Ok ((copied_input, true));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_type (input_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_input
+				(
+					input,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_type (input_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_input_ref
+				(
+					input,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_mut_type (input_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_input_ref_mut
+				(
+					input,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some (PinType {pinned_type, ..}) =
+			PinType::match_type (input_type)
+		{
+			if let Some ((from_type, to_type, value_transformer)) =
+				self . get_transformer_for_ref_type (&pinned_type)
+			{
+				return Ok
+				((
+					value_transformer . transform_input_pin_ref
+					(
+						input,
+						from_type,
+						&to_type
+					)?,
+					true
+				));
+			}
+			else if let Some ((from_type, to_type, value_transformer)) =
+				self . get_transformer_for_ref_mut_type (&pinned_type)
+			{
+				return Ok
+				((
+					value_transformer . transform_input_pin_ref_mut
+					(
+						input,
+						from_type,
+						&to_type
+					)?,
+					true
+				));
+			}
+
+			self . check_pinned_type (input_type, &pinned_type)?;
+		}
+		else if let Some (SharedType {kind, shared_type, ..}) =
+			SharedType::match_type (input_type)
+		{
+			if let Some ((from_type, to_type, value_transformer)) =
+				self . get_transformer_for_type (&shared_type)
+			{
+				return Ok
+				((
+					value_transformer . transform_input_shared
+					(
+						input,
+						&kind . get_path (),
+						from_type,
+						&to_type
+					)?,
+					true
+				));
+			}
+
+			self . check_shared_type (&shared_type)?;
+		}
+		else if let Some (callback) = CallbackType::match_type (input_type)
+		{
+			return self . transform_input_callback (input, callback);
+		}
+		else if let Type::ImplTrait (impl_trait) = input_type
+		{
+			return self . transform_input_impl_trait (input, impl_trait);
+		}
+		else if let Type::BareFn (bare_fn) = input_type
+		{
+			return self . transform_input_fn_pointer (input, bare_fn);
+		}
+		else if let Some (BoxType {boxed_type, ..}) =
+			BoxType::match_type (input_type)
+		{
+			return self . transform_input_box (input, &boxed_type);
+		}
+		else if let Some (OptionType {option_type, ..}) =
+			OptionType::match_type (input_type)
+		{
+			return self . transform_input_option (input, &option_type);
+		}
+		else if let Some (ResultType {result_type, error_type, ..}) =
+			ResultType::match_type (input_type)
+		{
+			return self . transform_input_result (input, &result_type, &error_type);
+		}
+		else if let Some (PollType {poll_type, ..}) =
+			PollType::match_type (input_type)
+		{
+			return self . transform_input_poll (input, &poll_type);
+		}
+		else if let Some (ControlFlowType {break_type, continue_type, ..}) =
+			ControlFlowType::match_type (input_type)
+		{
+			return self . transform_input_control_flow
+			(
+				input,
+				&break_type,
+				continue_type . as_ref ()
+			);
+		}
+		else if let Some (BoundType {bound_type, ..}) =
+			BoundType::match_type (input_type)
+		{
+			return self . transform_input_bound (input, &bound_type);
+		}
+		else if let Some (TupleType {types, ..}) =
+			TupleType::match_type (input_type)
+		{
+			return self . transform_input_tuple (input, &types);
+		}
+		else if let Some (ArrayType {ty, ..}) =
+			ArrayType::match_type (input_type)
+		{
+			return self . transform_input_array (input, &ty);
+		}
+
+		self . check_borrowed_shared_type (input_type)?;
+		self . check_converted_params (input_type)?;
+		self . check_callback_type (input_type)?;
+
+		Ok ((input, false))
+	}
+
+	fn transform_output_box (&mut self, output: Expr, inner_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		let inner_output = parse_quote! ((*#output));
+
+		if let (inner_output, true) =
+			self . transform_output (inner_output, inner_type)?
+		{
+			let output = parse_quote!
+			(
+				Box::new (#inner_output)
+			);
+
+			return Ok ((output, true));
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output_option (&mut self, output: Expr, inner_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		let inner_output = parse_quote! (v);
+
+		if let (inner_output, true) =
+			self . transform_output (inner_output, inner_type)?
+		{
+			let output = parse_quote!
+			(
+				#output . map (|v| #inner_output)
+			);
+
+			return Ok ((output, true));
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output_result
+	(
+		&mut self,
+		output: Expr,
+		ok_type: &Type,
+		err_type: &Type
+	)
+	-> Result <(Expr, bool)>
+	{
+		let (ok_output, ok_transformed) =
+			self . transform_output (parse_quote! (v), ok_type)?;
+		let (err_output, err_transformed) =
+			self . transform_output (parse_quote! (e), err_type)?;
+
+		let mut output = output;
+
+		if ok_transformed
+		{
+			output = parse_quote! (#output . map (|v| #ok_output));
+		}
+
+		if err_transformed
+		{
+			output = parse_quote! (#output . map_err (|e| #err_output));
+		}
+
+		Ok ((output, ok_transformed || err_transformed))
+	}
+
+	fn transform_output_poll (&mut self, output: Expr, inner_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		let inner_output = parse_quote! (v);
+
+		if let (inner_output, true) =
+			self . transform_output (inner_output, inner_type)?
+		{
+			let output = parse_quote!
+			(
+				#output . map (|v| #inner_output)
+			);
+
+			return Ok ((output, true));
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output_control_flow
+	(
+		&mut self,
+		output: Expr,
+		break_type: &Type,
+		continue_type: Option <&Type>
+	)
+	-> Result <(Expr, bool)>
+	{
+		let (break_output, break_transformed) =
+			self . transform_output (parse_quote! (v), break_type)?;
+		let (continue_output, continue_transformed) = match continue_type
+		{
+			Some (continue_type) =>
+				self . transform_output (parse_quote! (v), continue_type)?,
+			None => (parse_quote! (v), false)
+		};
+
+		if break_transformed || continue_transformed
+		{
+			let output = parse_quote!
+			(
+				match #output
+				{
+					::core::ops::ControlFlow::Continue (v) =>
+						::core::ops::ControlFlow::Continue (#continue_output),
+					::core::ops::ControlFlow::Break (v) =>
+						::core::ops::ControlFlow::Break (#break_output)
+				}
+			);
+
+			return Ok ((output, true));
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output_bound (&mut self, output: Expr, inner_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		let inner_output = parse_quote! (v);
+
+		if let (inner_output, true) =
+			self . transform_output (inner_output, inner_type)?
+		{
+			let output = parse_quote!
+			(
+				match #output
+				{
+					::core::ops::Bound::Included (v) =>
+						::core::ops::Bound::Included (#inner_output),
+					::core::ops::Bound::Excluded (v) =>
+						::core::ops::Bound::Excluded (#inner_output),
+					::core::ops::Bound::Unbounded => ::core::ops::Bound::Unbounded
+				}
+			);
+
+			return Ok ((output, true));
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output_tuple
+	(
+		&mut self,
+		output: Expr,
+		inner_types: &Punctuated <Type, Token! [,]>
+	)
+	-> Result <(Expr, bool)>
+	{
+		let mut any_transformed: bool = false;
+
+		let expr_var: Ident = parse_quote! (v);
+		let mut inner_outputs = Punctuated::<Expr, Token! [,]>::new ();
+
+		for i in 0..(inner_types . len ())
+		{
+			let idx = Index::from (i);
+			let inner_output = parse_quote! (#expr_var . #idx);
+			let inner_type = &inner_types [i];
+			let (inner_output, output_transformed) =
+				self . transform_output (inner_output, inner_type)?;
+
+			inner_outputs . push (inner_output);
+			any_transformed |= output_transformed;
+		}
+
+		if any_transformed
+		{
+			let output = parse_quote!
+			(
+				{
+					let #expr_var = #output;
+
+					(#inner_outputs)
+				}
+			);
+
+			Ok ((output, true))
+		}
+		else
+		{
+			Ok ((output, false))
+		}
+	}
+
+	fn transform_output_array (&mut self, output: Expr, inner_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		let inner_output = parse_quote! (v);
+
+		if let (inner_output, true) =
+			self . transform_output (inner_output, inner_type)?
+		{
+			let output = parse_quote!
+			(
+				#output . map (|v| #inner_output)
+			);
+
+			return Ok ((output, true))
+		}
+
+		Ok ((output, false))
+	}
+
+	// Only the transformations that can be evaluated in constant expressions are
+	// used for associated constants.  Closures can't be called, so wrappers are
+	// matched on instead of mapped over.
+	fn transform_output_const (&mut self, output: Expr, output_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		if let Some ((from_type, _, value_transformer)) =
+			self . get_transformer_for_type (output_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_output_const (output, from_type)?,
+				true
+			));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_type (output_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_output_ref
+				(
+					output,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some (OptionType {option_type, ..}) =
+			OptionType::match_type (output_type)
+		{
+			if let (inner_output, true) =
+				self . transform_output (parse_quote! (v), &option_type)?
+			{
+				let output = parse_quote!
+				(
+					match #output
+					{
+						Some (v) => Some (#inner_output),
+						None => None
+					}
+				);
+
+				return Ok ((output, true));
+			}
+		}
+		else if let Some (ResultType {result_type, error_type, ..}) =
+			ResultType::match_type (output_type)
+		{
+			let (ok_output, ok_transformed) =
+				self . transform_output (parse_quote! (v), &result_type)?;
+			let (err_output, err_transformed) =
+				self . transform_output (parse_quote! (e), &error_type)?;
+
+			if ok_transformed || err_transformed
+			{
+				let output = parse_quote!
+				(
+					match #output
+					{
+						Ok (v) => Ok (#ok_output),
+						Err (e) => Err (#err_output)
+					}
+				);
+
+				return Ok ((output, true));
+			}
+		}
+		else if let Some (TupleType {types, ..}) =
+			TupleType::match_type (output_type)
+		{
+			return self . transform_output_tuple (output, &types);
+		}
+		else if let Some (ArrayType {ty, ..}) = ArrayType::match_type (output_type)
+		{
+			let is_transparent = self
+				. get_transformer_for_type (&ty)
+				. map (|(_, _, value_transformer)| value_transformer . is_transparent ());
+
+			if is_transparent == Some (true)
+			{
+				// Arrays can't be mapped over in constant expressions, but a
+				// transparent receiver shares its layout with the delegated type,
+				// so the whole array can be reinterpreted.
+				let delegated_type = self . fold_delegated_type (output_type . clone ());
+
+				let output = parse_quote!
+				(
+					unsafe
+					{
+						std::ptr::read
+						(
+							&std::mem::ManuallyDrop::new (#output)
+								as *const std::mem::ManuallyDrop <#delegated_type>
+								as *const #output_type
+						)
+					}
+				);
+
+				return Ok ((output, true));
+			}
+		}
+
+		if self . fold_delegated_type (output_type . clone ()) != *output_type
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					output_type,
+					"This type can't be transformed in a constant expression"
+				)
+			);
+		}
+
+		Ok ((output, false))
+	}
+
+	fn transform_output (&mut self, output: Expr, output_type: &Type)
+	-> Result <(Expr, bool)>
+	{
+		if self . is_const
+		{
+			return self . transform_output_const (output, output_type);
+		}
+
+		let in_callback = self . in_callback;
+
+		if let Some (converted_output) =
+			self . transform_output_converted_param (output . clone (), output_type)?
+		{
+			return Ok ((converted_output, true));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_type (output_type)
+		{
+			return Ok
+			((
+				value_transformer . transform_output
+				(
+					output,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_type (output_type)
+		{
+			Self::check_borrowed_output (in_callback, output_type)?;
+
+			return Ok
+			((
+				value_transformer . transform_output_ref
+				(
+					output,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some ((from_type, to_type, value_transformer)) =
+			self . get_transformer_for_ref_mut_type (output_type)
+		{
+			Self::check_borrowed_output (in_callback, output_type)?;
+
+			return Ok
+			((
+				value_transformer . transform_output_ref_mut
+				(
+					output,
+					from_type,
+					&to_type
+				)?,
+				true
+			));
+		}
+		else if let Some (PinType {pinned_type, ..}) =
+			PinType::match_type (output_type)
+		{
+			self . check_pinned_type (output_type, &pinned_type)?;
+		}
+		else if let Some (SharedType {kind, shared_type, ..}) =
+			SharedType::match_type (output_type)
+		{
+			if let Some ((from_type, to_type, value_transformer)) =
+				self . get_transformer_for_type (&shared_type)
+			{
+				return Ok
+				((
+					value_transformer . transform_output_shared
+					(
+						output,
+						&kind . get_path (),
+						from_type,
+						&to_type
+					)?,
+					true
+				));
+			}
+
+			self . check_shared_type (&shared_type)?;
+		}
+		else if let Some (BoxType {boxed_type, ..}) =
+			BoxType::match_type (output_type)
+		{
+			return self . transform_output_box (output, &boxed_type);
+		}
+		else if let Some (OptionType {option_type, ..}) =
+			OptionType::match_type (output_type)
+		{
+			return self . transform_output_option (output, &option_type);
+		}
+		else if let Some (ResultType {result_type, error_type, ..}) =
+			ResultType::match_type (output_type)
+		{
+			return self . transform_output_result (output, &result_type, &error_type);
+		}
+		else if let Some (PollType {poll_type, ..}) =
+			PollType::match_type (output_type)
+		{
+			return self . transform_output_poll (output, &poll_type);
+		}
+		else if let Some (ControlFlowType {break_type, continue_type, ..}) =
+			ControlFlowType::match_type (output_type)
+		{
+			return self . transform_output_control_flow
+			(
+				output,
+				&break_type,
+				continue_type . as_ref ()
+			);
+		}
+		else if let Some (BoundType {bound_type, ..}) =
+			BoundType::match_type (output_type)
+		{
+			return self . transform_output_bound (output, &bound_type);
+		}
+		else if let Some (TupleType {types, ..}) =
+			TupleType::match_type (output_type)
+		{
+			return self . transform_output_tuple (output, &types);
+		}
+		else if let Some (ArrayType {ty, ..}) =
+			ArrayType::match_type (output_type)
+		{
+			return self . transform_output_array (output, &ty);
+		}
+
+		self . check_borrowed_shared_type (output_type)?;
+		self . check_converted_params (output_type)?;
+		self . check_callback_type (output_type)?;
+
+		Ok ((output, false))
+	}
+
+	fn construct_arg (&mut self, input: &FnArg) -> Result <Expr>
+	{
+		match input
+		{
+			FnArg::Receiver (receiver) =>
+			{
+				let Receiver {self_token, ty, ..} = receiver;
+
+				self . is_argument = true;
+
+				let arg = self . transform_input
+				(
+					parse_quote! (#self_token),
+					ty . as_ref ()
+				)?
+					. 0;
+
+				Ok (arg)
+			},
+			FnArg::Typed (pat_type) =>
+			{
+				let PatType {pat, ty, ..} = pat_type;
+
+				self . is_argument = true;
+
+				let arg: Expr = self . transform_input
+				(
+					parse_quote! (#pat),
+					ty . as_ref ()
+				)?
+					. 0;
+
+				let is_untransformed =
+					arg . to_token_stream () . to_string () == pat . to_token_stream () . to_string ();
+
+				if ! self . shares_args || ! is_untransformed
+				{
+					return Ok (arg);
+				}
+
+				// Arguments passed along to several delegated calls are reborrowed
+				// or cloned for each of them.
+				let arg = match ty . as_ref ()
+				{
+					Type::Reference (TypeReference {mutability: Some (_), ..}) =>
+						parse_quote! (&mut *#pat),
+					Type::Reference (_) => arg,
+					_ => parse_quote! (std::clone::Clone::clone (&#pat))
+				};
+
+				Ok (arg)
+			}
+		}
+	}
+
+	// The bounds declared on a replaced associated type must hold for its
+	// replacement.  They're added to the implementation with the span of the
+	// replacement, so that errors point at the transformation specification.
+	fn add_associated_type_bounds
+	(
+		&mut self,
+		item_type: &TraitItemType,
+		assigned_type: &Type
+	)
+	-> Result <()>
+	{
+		let TraitItemType {ident, generics, bounds, ..} = item_type;
+
+		let Some (associated_type_transformer) =
+			self . associated_type_transformers . get (ident)
+		else { return Ok (()); };
+
+		if bounds . is_empty () { return Ok (()); }
+
+		// Bounds can only be quantified over lifetimes.
+		if let Some (param) = generics
+			. params
+			. iter ()
+			. find (|param| ! matches! (param, GenericParam::Lifetime (_)))
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					param,
+					"Bounds on replaced associated types can't be required of the replacement if the associated type has type or const parameters"
+				)
+			);
+		}
+
+		// Nor can they be made conditional, though requirements that only
+		// restrict the lifetimes can be left out, as they only make the bounds
+		// stricter.
+		if let Some (predicate) = generics
+			. where_clause
+			. iter ()
+			. flat_map (|where_clause| &where_clause . predicates)
+			. find
+			(
+				|predicate| match predicate
+				{
+					WherePredicate::Lifetime (_) => false,
+					WherePredicate::Type (predicate_type) => predicate_type
+						. bounds
+						. iter ()
+						. any (|bound| ! matches! (bound, TypeParamBound::Lifetime (_))),
+					_ => true
+				}
+			)
+		{
+			return Err
+			(
+				Error::new_spanned
+				(
+					predicate,
+					"Bounds on replaced associated types can't be required of the replacement if the associated type's where clause has trait bounds"
+				)
+			);
+		}
+
+		let span = associated_type_transformer . replacement_type . span ();
+
+		// The lifetimes of the associated type in the specification are
+		// renamed to those of its definition, but any others are kept.
+		let specified_generics = &associated_type_transformer . associated_type . generics;
+
+		let mut lifetimes: Vec <GenericParam> = associated_type_transformer
+			. lifetimes
+			. iter ()
+			. flat_map (|lifetimes| lifetimes . lifetimes . iter () . cloned ())
+			. filter
+			(
+				|param| ! specified_generics
+					. params
+					. iter ()
+					. any (|specified_param| specified_param == param)
+			)
+			. collect ();
+		lifetimes . extend (generics . lifetimes () . cloned () . map (GenericParam::Lifetime));
+
+		let lifetimes: Option <BoundLifetimes> = (! lifetimes . is_empty ())
+			. then (|| parse_quote! (for <#(#lifetimes),*>));
+
+		// Other associated types named in the bounds are resolved to what
+		// they're assigned in the implementation.
+		let bounds =
+		{
+			let forwarded_trait = self . get_forwarded_trait ()?;
+			let Self {associated_type_transformers, delegated_type, ..} = &*self;
+
+			let mut type_transformer = TypeTransformer::new
+			(
+				|ty| AssociatedType::match_type_of (ty, forwarded_trait) . and_then
+				(
+					|AssociatedType {ident, generics, ..}| associated_type_transformers
+						. get_assigned_type
+						(
+							&ident,
+							&generics,
+							delegated_type,
+							forwarded_trait
+						)
+						. ok ()
+				)
+			);
+
+			bounds
+				. iter ()
+				. map (|bound| type_transformer . fold_type_param_bound (bound . clone ()))
+				. collect::<Punctuated <TypeParamBound, Token! [+]>> ()
+		};
+
+		let predicate = respan
+		(
+			quote! (#lifetimes #assigned_type: #bounds),
+			span
+		);
+
+		self . add_bridge_predicate (syn::parse2 (predicate)?);
+
+		Ok (())
+	}
+
+	fn transform_item_type (&mut self, item_type: TraitItemType)
+	-> Result <ImplItemType>
+	{
+		let TraitItemType {ident, generics, ..} = &item_type;
+
+		let assigned_type = self
+			. associated_type_transformers
+			. get_assigned_type
+			(
+				ident,
+				generics,
+				&self . delegated_type,
+				self . get_forwarded_trait ()?
+			)?;
+
+		self . add_associated_type_bounds (&item_type, &assigned_type)?;
+
+		let (impl_generics, _, where_clause) = generics . split_for_impl ();
+
+		let item_type = parse_quote!
+		{
+			type #ident #impl_generics = #assigned_type
+			#where_clause;
+		};
+
+		Ok (item_type)
+	}
+
+	fn add_bridge_predicate (&mut self, predicate: WherePredicate)
+	{
+		if ! self . bridge_predicates . iter () . any (|p| *p == predicate)
+		{
+			self . bridge_predicates . push (predicate);
+		}
+	}
+
+	// Bounds on the method's generics that mention `Self` have to be checked
+	// against the delegated type instead.  Bounds that don't involve the
+	// method's generics are added to the method, and method generic
+	// parameters bounded by `Into <Self>` or `From <Self>` are converted.
+	// Anything else can't be expressed, and is rejected.
+	fn bridge_method_generics (&mut self, generics: &Generics) -> Result <()>
+	{
+		let method_idents = get_param_idents (generics);
+
+		let mut predicates: Vec <(Type, Vec <TypeParamBound>, TokenStream)> =
+			Vec::new ();
+
+		for param in &generics . params
+		{
+			if let GenericParam::Type (type_param) = param
+			{
+				let ident = &type_param . ident;
+				predicates . push
+				((
+					parse_quote! (#ident),
+					type_param . bounds . iter () . cloned () . collect (),
+					TokenStream::new ()
+				));
+			}
+		}
+
+		for predicate in generics . where_clause . iter () . flat_map (|w| &w . predicates)
+		{
+			if let WherePredicate::Type (predicate_type) = predicate
+			{
+				predicates . push
+				((
+					predicate_type . bounded_ty . clone (),
+					predicate_type . bounds . iter () . cloned () . collect (),
+					predicate_type . lifetimes . to_token_stream ()
+				));
+			}
+		}
+
+		// Find the converted parameters first, as their other bounds are
+		// checked against the delegated type.
+		for (bounded_ty, bounds, _) in &predicates
+		{
+			let Type::Path (type_path) = bounded_ty else { continue; };
+			let Some (ident) = type_path . path . get_ident () else { continue; };
+			if ! method_idents . contains (ident) { continue; }
+
+			for bound in bounds
+			{
+				let Some ((trait_ident, via_type)) = match_conversion_bound (bound)
+				else { continue; };
+
+				let Some ((_, delegated_type, _)) =
+					self . get_transformer_for_type (&via_type)
+				else { continue; };
+
+				if self . converted_params . iter () . any (|param| param . ident == *ident)
+				{
+					return Err
+					(
+						Error::new_spanned
+						(
+							bound,
+							"Method generic parameters can only be converted through one transformed type"
+						)
+					);
+				}
+
+				self . converted_params . push
+				(
+					ConvertedParam
+					{
+						ident: ident . clone (),
+						via_type,
+						delegated_type,
+						is_input: trait_ident == "Into"
+					}
+				);
+			}
+		}
+
+		let converted_idents: Vec <Ident> = self
+			. converted_params
+			. iter ()
+			. map (|param| param . ident . clone ())
+			. collect ();
+
+		for (bounded_ty, bounds, lifetimes) in predicates
+		{
+			let converted_param = self
+				. converted_params
+				. iter ()
+				. find (|param| param . matches (&bounded_ty))
+				. map (|param| param . delegated_type . clone ());
+
+			for bound in bounds
+			{
+				let is_conversion = converted_param . is_some ()
+					&& match_conversion_bound (&bound) . is_some_and
+					(
+						|(_, via_type)|
+							self . fold_delegated_type (via_type . clone ()) != via_type
+					);
+
+				if is_conversion { continue; }
+
+				let delegated_bounded_ty = match &converted_param
+				{
+					Some (delegated_type) => delegated_type . clone (),
+					None => self . fold_delegated_type (bounded_ty . clone ())
+				};
+
+				let delegated_bound = self
+					. get_delegated_type_transformer ()
+					. fold_type_param_bound (bound . clone ());
+
+				// Bounds that don't mention `Self` hold on both sides, unless
+				// they involve a parameter that's instantiated differently.
+				let unchanged = converted_param . is_none ()
+					&& delegated_bounded_ty == bounded_ty
+					&& delegated_bound == bound
+					&& ! mentions_any (bound . to_token_stream (), &converted_idents)
+					&& ! mentions_any (bounded_ty . to_token_stream (), &converted_idents);
+
+				if unchanged { continue; }
+
+				let predicate: WherePredicate = parse_quote!
+				(
+					#lifetimes #delegated_bounded_ty: #delegated_bound
+				);
+
+				if mentions_any (predicate . to_token_stream (), &method_idents)
+				{
+					let message = if mentions_any
+					(
+						predicate . to_token_stream (),
+						&converted_idents
+					)
+					{
+						"Method generic parameters converted through `Self` can't appear in bounds that mention `Self` or other method generic parameters"
+					}
+					else
+					{
+						"Bounds on method generic parameters can only mention `Self` as `Into <Self>` or `From <Self>`"
+					};
+
+					return Err (Error::new_spanned (bound, message));
+				}
+
+				// Restricting the implementation instead would change which
+				// delegated types it applies to.
+				if mentions_any (predicate . to_token_stream (), &self . impl_params)
+				{
+					return Err
+					(
+						Error::new_spanned
+						(
+							bound,
+							"Bounds on forwarded methods that mention `Self` can't be restated for a generic delegated type"
+						)
+					);
+				}
+
+				if ! self . method_predicates . iter () . any (|p| *p == predicate)
+				{
+					self . method_predicates . push (predicate);
+				}
+			}
 		}
 
-		Ok ((output, false))
+		Ok (())
 	}
 
-	fn transform_output (&mut self, output: Expr, output_type: &Type)
-	-> Result <(Expr, bool)>
+	fn transform_input_converted_param (&mut self, input: Expr, input_type: &Type)
+	-> Result <Option <Expr>>
 	{
-		if let Some ((from_type, to_type, value_transformer)) =
-			self . get_transformer_for_type (output_type)
-		{
-			return Ok
-			((
-				value_transformer . transform_output
-				(
-					output,
-					from_type,
-					&to_type
-				)?,
-				true
-			));
-		}
-		else if let Some (_) = self . get_transformer_for_ref_type (output_type)
+		let Some (converted_param) = self
+			. converted_params
+			. iter ()
+			. find (|param| param . matches (input_type))
+		else { return Ok (None); };
+
+		if ! converted_param . is_input
 		{
 			return Err
 			(
 				Error::new_spanned
 				(
-					output_type,
-					"Borrowed return values cannot be transformed for forwarding"
+					input_type,
+					"Method generic parameters bounded by `From <Self>` can only be forwarded in return types"
 				)
 			);
 		}
-		else if let Some (_) =
-			self . get_transformer_for_ref_mut_type (output_type)
+
+		let via_type = converted_param . via_type . clone ();
+
+		let input = parse_quote!
+		(
+			<#input_type as std::convert::Into <#via_type>>::into (#input)
+		);
+
+		Ok (Some (self . transform_input (input, &via_type)? . 0))
+	}
+
+	fn transform_output_converted_param (&mut self, output: Expr, output_type: &Type)
+	-> Result <Option <Expr>>
+	{
+		let Some (converted_param) = self
+			. converted_params
+			. iter ()
+			. find (|param| param . matches (output_type))
+		else { return Ok (None); };
+
+		if converted_param . is_input
 		{
 			return Err
 			(
 				Error::new_spanned
 				(
 					output_type,
-					"Borrowed return values cannot be transformed for forwarding"
+					"Method generic parameters bounded by `Into <Self>` can only be forwarded in argument types"
 				)
 			);
 		}
-		else if let Some (BoxType {boxed_type, ..}) =
-			BoxType::match_type (output_type)
-		{
-			return self . transform_output_box (output, &boxed_type);
-		}
-		else if let Some (OptionType {option_type, ..}) =
-			OptionType::match_type (output_type)
-		{
-			return self . transform_output_option (output, &option_type);
-		}
-		else if let Some (ResultType {result_type, ..}) =
-			ResultType::match_type (output_type)
-		{
-			return self . transform_output_result (output, &result_type);
-		}
-		else if let Some (TupleType {types, ..}) =
-			TupleType::match_type (output_type)
-		{
-			return self . transform_output_tuple (output, &types);
-		}
-		else if let Some (ArrayType {ty, ..}) =
-			ArrayType::match_type (output_type)
-		{
-			return self . transform_output_array (output, &ty);
-		}
 
-		Ok ((output, false))
-	}
-
-	fn construct_arg (&mut self, input: &FnArg) -> Result <Expr>
-	{
-		match input
-		{
-			FnArg::Receiver (receiver) =>
-			{
-				let Receiver {self_token, ty, ..} = receiver;
+		let via_type = converted_param . via_type . clone ();
+		let output = self . transform_output (output, &via_type)? . 0;
 
-				let arg = self . transform_input
+		Ok
+		(
+			Some
+			(
+				parse_quote!
 				(
-					parse_quote! (#self_token),
-					ty . as_ref ()
-				)?
-					. 0;
+					<#output_type as std::convert::From <#via_type>>::from (#output)
+				)
+			)
+		)
+	}
 
-				Ok (arg)
-			},
-			FnArg::Typed (pat_type) =>
-			{
-				let PatType {pat, ty, ..} = pat_type;
+	fn check_converted_params (&self, ty: &Type) -> Result <()>
+	{
+		let converted_idents: Vec <Ident> = self
+			. converted_params
+			. iter ()
+			. map (|param| param . ident . clone ())
+			. collect ();
 
-				let arg = self . transform_input
+		if mentions_any (ty . to_token_stream (), &converted_idents)
+		{
+			return Err
+			(
+				Error::new_spanned
 				(
-					parse2 (pat . to_token_stream ())?,
-					ty . as_ref ()
-				)?
-					. 0;
-
-				Ok (arg)
-			}
+					ty,
+					"Method generic parameters converted through `Self` can only be forwarded where `Self` could be"
+				)
+			);
 		}
+
+		Ok (())
 	}
 
-	fn transform_item_type (&self, item_type: TraitItemType)
-	-> Result <ImplItemType>
+	// Methods named by a single ident are called on the delegated type as
+	// inherent methods (or methods of traits in scope), otherwise the method is
+	// called through the trait named by the rest of the path, which the
+	// delegated type then has to implement.
+	fn get_method_call
+	(
+		&mut self,
+		method: &Path,
+		turbofish: Option <TokenStream>,
+		args: Punctuated <Expr, Token! [,]>
+	)
+	-> Expr
 	{
-		let TraitItemType {ident, generics, ..} = item_type;
+		let delegated_type = self . delegated_type . clone ();
 
-		let (impl_generics, _, where_clause) =
-			generics . split_for_impl ();
-
-		let assigned_type = self
-			. associated_type_transformers
-			. get_assigned_type
+		// Inherent methods are called by path rather than with method call
+		// syntax, so that a missing method isn't looked up through
+		// auto-dereferencing.
+		if let Some (method_ident) = method . get_ident ()
+		{
+			return parse_quote_spanned!
 			(
-				&ident,
-				&generics,
-				&self . delegated_type,
-				&self . forwarded_trait
-			)?;
+				method . span () =>
+				<#delegated_type>::#method_ident #turbofish (#args)
+			);
+		}
 
-		let item_type = parse_quote!
+		let method_count = method . segments . len ();
+		let method_ident = &method . segments [method_count - 1] . ident;
+		let method_trait = Path
 		{
-			type #ident #impl_generics = #assigned_type
-			#where_clause;
+			leading_colon: method . leading_colon,
+			segments: method
+				. segments
+				. iter ()
+				. take (method_count - 1)
+				. cloned ()
+				. collect ()
 		};
 
-		Ok (item_type)
+		self . add_bridge_predicate (parse_quote! (#delegated_type: #method_trait));
+
+		// The other method's signature isn't known here, so mismatches are
+		// left to the compiler, which reports them at the method's path.
+		parse_quote_spanned!
+		(
+			method . span () =>
+			<#delegated_type as #method_trait>::#method_ident #turbofish (#args)
+		)
 	}
 
-	fn transform_item_fn (&mut self, item_fn: TraitItemFn) -> Result <ImplItemFn>
+	// Builds the body of a forwarded method with renamed arguments, making the
+	// delegated call and transforming its output.  The call is made to the
+	// delegated type's implementation of the same method of the forwarded trait,
+	// unless another method is given.
+	pub fn transform_call
+	(
+		&mut self,
+		sig: &Signature,
+		shares_args: bool,
+		method: Option <&Path>
+	)
+	-> Result <Expr>
 	{
-		let TraitItemFn
-		{
-			sig: Signature
-			{
-				constness,
-				asyncness,
-				unsafety,
-				ident,
-				generics,
-				inputs,
-				output,
-				..
-			},
-			..
-		}
-			= item_fn;
+		let Signature {unsafety, ident, generics, inputs, output, ..} = sig;
+
+		self . shares_args = shares_args;
+
+		self . bridge_method_generics (generics)?;
 
 		let mut args = Punctuated::<Expr, Token! [,]>::new ();
-		for input in &inputs
+		for input in inputs
 		{
 			args . push (self . construct_arg (input)?);
 		}
 
-		let call_expr =
-		{
-			let Self {delegated_type, forwarded_trait, ..} = &*self;
-
-			parse_quote!
+		// Lifetimes can't be named explicitly if they're late-bound, and may all
+		// be omitted, so only type and const arguments are passed along.
+		// Argument-position `impl Trait` types aren't generic parameters here,
+		// and are inferred.
+		// Converted parameters are instantiated with the delegated type.
+		let converted_params = &self . converted_params;
+
+		let method_args: Punctuated <Type, Token! [,]> = generics
+			. params
+			. iter ()
+			. filter_map
 			(
-				<#delegated_type as #forwarded_trait>::#ident (#args)
+				|param| match param
+				{
+					GenericParam::Lifetime (_) => None,
+					GenericParam::Type (type_param) =>
+					{
+						let ident = &type_param . ident;
+
+						Some
+						(
+							converted_params
+								. iter ()
+								. find (|param| param . ident == *ident)
+								. map_or_else
+								(
+									|| parse_quote! (#ident),
+									|param| param . delegated_type . clone ()
+								)
+						)
+					},
+					GenericParam::Const (const_param) =>
+					{
+						let ident = &const_param . ident;
+						Some (parse_quote! (#ident))
+					}
+				}
 			)
+			. collect ();
+
+		let turbofish = (! method_args . is_empty ())
+			. then (|| quote! (::<#method_args>));
+
+		let call_expr = match method
+		{
+			None =>
+			{
+				let forwarded_trait = self . get_forwarded_trait ()?;
+				let delegated_type = &self . delegated_type;
+
+				parse_quote!
+				(
+					<#delegated_type as #forwarded_trait>::#ident #turbofish (#args)
+				)
+			},
+			Some (method) => self . get_method_call (method, turbofish, args)
 		};
 
-		let body_expr = if let ReturnType::Type (_, boxed_ty) = &output
+		// Calling an unsafe method needs an `unsafe` block of its own, even in
+		// the body of an unsafe method, as of the 2024 edition.
+		let call_expr = match unsafety
+		{
+			Some (_) => parse_quote! (unsafe { #call_expr }),
+			None => call_expr
+		};
+
+		let body_expr = if let ReturnType::Type (_, boxed_ty) = output
 		{
 			self . transform_output (call_expr, boxed_ty . as_ref ())? . 0
 		}
@@ -645,9 +2385,68 @@ impl Transformer
 			call_expr
 		};
 
+		self . converted_params . clear ();
+
+		let copy_ins = std::mem::take (&mut self . copy_ins);
+		let copy_outs = std::mem::take (&mut self . copy_outs);
+
+		let body_expr = if copy_outs . is_empty ()
+		{
+			body_expr
+		}
+		else
+		{
+			let output_ident = Ident::new ("output", Span::mixed_site ());
+
+			parse_quote!
+			({
+				#(#copy_ins)*
+
+				let #output_ident = #body_expr;
+
+				#(#copy_outs)*
+
+				#output_ident
+			})
+		};
+
+		Ok (body_expr)
+	}
+
+	pub fn build_item_fn
+	(
+		mut sig: Signature,
+		predicates: impl IntoIterator <Item = WherePredicate>,
+		body_expr: Expr
+	)
+	-> ImplItemFn
+	{
+		for predicate in predicates
+		{
+			let where_clause = sig . generics . make_where_clause ();
+
+			if ! where_clause . predicates . iter () . any (|p| *p == predicate)
+			{
+				where_clause . predicates . push (predicate);
+			}
+		}
+
+		let Signature
+		{
+			constness,
+			asyncness,
+			unsafety,
+			ident,
+			generics,
+			inputs,
+			output,
+			..
+		}
+			= sig;
+
 		let (impl_generics, _, where_clause) = generics . split_for_impl ();
 
-		let item_fn = parse_quote!
+		parse_quote!
 		{
 			#constness #asyncness #unsafety fn #ident #impl_generics (#inputs)
 			#output
@@ -655,29 +2454,50 @@ impl Transformer
 			{
 				#body_expr
 			}
-		};
-
-		Ok (item_fn)
+		}
 	}
 
-	fn transform_item_const (&self, item_const: TraitItemConst)
-	-> ImplItemConst
+	fn transform_item_fn (&mut self, item_fn: TraitItemFn) -> Result <ImplItemFn>
 	{
-		let Self {delegated_type, forwarded_trait, ..} = self;
+		let sig = Signature {inputs: rename_args (item_fn . sig . inputs), .. item_fn . sig};
+
+		let body_expr = self . transform_call (&sig, false, None)?;
+		let predicates = self . take_method_predicates ();
 
+		Ok (Self::build_item_fn (sig, predicates, body_expr))
+	}
+
+	fn transform_item_const (&mut self, item_const: TraitItemConst)
+	-> Result <ImplItemConst>
+	{
 		let TraitItemConst {ident, generics, ty, ..} = item_const;
 
 		let (impl_generics, type_generics, where_clause) =
 			generics . split_for_impl ();
 
-		let item_const = parse_quote!
+		let value =
 		{
-			const #ident #impl_generics: #ty =
+			let forwarded_trait = self . get_forwarded_trait ()?;
+			let delegated_type = &self . delegated_type;
+
+			parse_quote!
+			(
 				<#delegated_type as #forwarded_trait>::#ident #type_generics
+			)
+		};
+
+		self . is_const = true;
+		let value = self . transform_output (value, &ty);
+		self . is_const = false;
+		let (value, _) = value?;
+
+		let item_const = parse_quote!
+		{
+			const #ident #impl_generics: #ty = #value
 			#where_clause;
 		};
 
-		item_const
+		Ok (item_const)
 	}
 
 	pub fn transform_trait_item (&mut self, item: TraitItem)
@@ -687,7 +2507,7 @@ impl Transformer
 		{
 			TraitItem::Const (item_const) => Ok
 			(
-				ImplItem::Const (self . transform_item_const (item_const))
+				ImplItem::Const (self . transform_item_const (item_const)?)
 			),
 			TraitItem::Fn (item_fn) => Ok
 			(
@@ -708,19 +2528,188 @@ impl Transformer
 		}
 	}
 
+	// Associated types of supertraits named in the trait's items are equal to
+	// the delegated type's, as long as the supertrait is forwarded the same way,
+	// but this has to be stated for it to be seen in generic implementations.
+	// Supertraits named in qualified paths are used as is, otherwise only the
+	// trait's sole supertrait is considered.  Associated types that are
+	// replaced are transformed instead.
+	pub fn add_supertrait_predicates
+	(
+		&mut self,
+		where_clause: Option <&WhereClause>,
+		items: &[TraitItem]
+	)
+	{
+		let own_types: Vec <Ident> = items
+			. iter ()
+			. filter_map
+			(
+				|item| match item
+				{
+					TraitItem::Type (item_type) => Some (item_type . ident . clone ()),
+					_ => None
+				}
+			)
+			. collect ();
+
+		let mut supertrait_types: Vec <Ident> = Vec::new ();
+		let mut qualified_types: Vec <(Path, Vec <Ident>)> = Vec::new ();
+
+		{
+			let Self {associated_type_transformers, forwarded_trait, ..} = &*self;
+
+			let mut type_transformer = TypeTransformer::new
+			(
+				|ty|
+				{
+					if let Some (AssociatedType {ident, ..}) =
+						AssociatedType::match_type (ty)
+					{
+						if ! own_types . contains (&ident)
+							&& ! supertrait_types . contains (&ident)
+							&& associated_type_transformers . get (&ident) . is_none ()
+						{
+							supertrait_types . push (ident);
+						}
+					}
+					else if let Some (qualified) = QualifiedAssociatedType::match_type (ty)
+					{
+						if forwarded_trait
+							. as_ref ()
+							. is_some_and (|forwarded_trait| qualified . is_of (forwarded_trait))
+						{
+							return None;
+						}
+
+						let QualifiedAssociatedType {trait_path, ident, ..} = qualified;
+
+						if associated_type_transformers . get (&ident) . is_some ()
+						{
+							return None;
+						}
+
+						match qualified_types
+							. iter_mut ()
+							. find (|(path, _)| *path == trait_path)
+						{
+							Some ((_, idents)) => if ! idents . contains (&ident)
+							{
+								idents . push (ident);
+							},
+							None => qualified_types . push ((trait_path, vec! [ident]))
+						}
+					}
+
+					None
+				}
+			);
+
+			for item in items
+			{
+				type_transformer . fold_trait_item (item . clone ());
+			}
+		}
+
+		for (trait_path, idents) in qualified_types
+		{
+			self . add_supertrait_predicate (&None, trait_path, idents);
+		}
+
+		if supertrait_types . is_empty () { return; }
+
+		let supertraits: Vec <&TraitBound> = where_clause
+			. iter ()
+			. flat_map (|where_clause| &where_clause . predicates)
+			. filter_map
+			(
+				|predicate| match predicate
+				{
+					WherePredicate::Type (predicate_type)
+					if predicate_type . bounded_ty == parse_quote! (Self) =>
+						Some (&predicate_type . bounds),
+					_ => None
+				}
+			)
+			. flatten ()
+			. filter_map
+			(
+				|bound| match bound
+				{
+					TypeParamBound::Trait (trait_bound)
+					if matches! (trait_bound . modifier, TraitBoundModifier::None) =>
+						Some (trait_bound),
+					_ => None
+				}
+			)
+			. collect ();
+
+		if let [supertrait] = supertraits . as_slice ()
+		{
+			self . add_supertrait_predicate
+			(
+				&supertrait . lifetimes,
+				supertrait . path . clone (),
+				supertrait_types
+			);
+		}
+	}
+
+	fn add_supertrait_predicate
+	(
+		&mut self,
+		lifetimes: &Option <BoundLifetimes>,
+		mut supertrait_path: Path,
+		idents: Vec <Ident>
+	)
+	{
+		let delegated_supertrait_path = self
+			. independent_type_transformers
+			. get_type_transformer ()
+			. fold_path (supertrait_path . clone ());
+
+		let delegated_type = &self . delegated_type;
+
+		add_associated_type_bindings
+		(
+			&mut supertrait_path,
+			idents . into_iter () . map
+			(
+				|ident|
+				{
+					let ty = parse_quote!
+					(
+						<#delegated_type as #delegated_supertrait_path>::#ident
+					);
+					(ident, ty)
+				}
+			)
+		);
+
+		self . add_bridge_predicate
+		(
+			parse_quote! (Self: #lifetimes #supertrait_path)
+		);
+	}
+
 	pub fn add_predicates
 	(
 		&self,
 		predicates: &mut Punctuated <WherePredicate, Token! [,]>
 	)
 	{
-		self . associated_type_transformers . add_predicates
-		(
-			predicates,
-			&self . delegated_type,
-			&self . forwarded_trait
-		);
+		if let Some (forwarded_trait) = &self . forwarded_trait
+		{
+			self . associated_type_transformers . add_predicates
+			(
+				predicates,
+				&self . delegated_type,
+				forwarded_trait
+			);
+		}
 
 		self . independent_type_transformers . add_predicates (predicates);
+
+		predicates . extend (self . bridge_predicates . iter () . cloned ());
 	}
 }
@@ -2,6 +2,7 @@ use syn::{Type, BoundLifetimes, Token};
 use syn_derive::{Parse, ToTokens};
 
 use crate::syn::member::Member;
+use crate::syn::copy_mode::CopyMode;
 use crate::syn::from_type::FromType;
 
 use crate::value_transformer
@@ -14,12 +15,15 @@ use crate::value_transformer
 use super::independent_type_transformer::IndependentTypeTransformer;
 use super::associated_type_transformer::AssociatedTypeTransformer;
 
-#[derive (Parse, ToTokens)]
+#[derive (Clone, Parse, ToTokens)]
 pub enum TransformType
 {
 	#[parse (peek = Token! [->])]
 	Conversion (Token! [->]),
 
+	#[parse (peek_func = |input| input . fork () . parse::<CopyMode> () . is_ok ())]
+	CopiedConversion (CopyMode, Token! [->]),
+
 	#[parse (peek = Token! [.])]
 	Member
 	{
@@ -37,6 +41,8 @@ impl TransformType
 		{
 			TransformType::Conversion (_) =>
 				ValueTransformer::from (ConversionTransformer::new ()),
+			TransformType::CopiedConversion (copy_mode, _) =>
+				ValueTransformer::from (ConversionTransformer::new_copied (copy_mode)),
 			TransformType::Member {member, ..} =>
 				ValueTransformer::from (MemberTransformer::new (member))
 		}
@@ -49,7 +55,7 @@ pub enum SpecializedTypeTransformer
 	Associated (AssociatedTypeTransformer)
 }
 
-#[derive (Parse, ToTokens)]
+#[derive (Clone, Parse, ToTokens)]
 pub struct AdditionalTypeTransformer
 {
 	pub lifetimes: Option <BoundLifetimes>,
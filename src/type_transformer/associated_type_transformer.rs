@@ -72,6 +72,12 @@ impl AssociatedTypeTransformers
 		);
 	}
 
+	pub fn get (&self, associated_type_ident: &Ident)
+	-> Option <&AssociatedTypeTransformer>
+	{
+		self . map . get (associated_type_ident)
+	}
+
 	pub fn get_assigned_type
 	(
 		&self,
@@ -98,6 +104,27 @@ impl AssociatedTypeTransformers
 		Ok (ty)
 	}
 
+	pub fn get_delegated_type
+	(
+		&self,
+		ty: &Type,
+		delegated_type: &Type,
+		forwarded_trait: &Path
+	)
+	-> Option <Type>
+	{
+		let AssociatedType {ident, generics, ..} =
+			AssociatedType::match_type_of (ty, forwarded_trait)?;
+
+		self
+			. map
+			. contains_key (&ident)
+			. then
+			(
+				|| parse_quote! (<#delegated_type as #forwarded_trait>::#ident #generics)
+			)
+	}
+
 	pub fn get_transformation <'a, 'b>
 	(
 		&'a mut self,
@@ -108,7 +135,7 @@ impl AssociatedTypeTransformers
 	-> Option <(&'b Type, Type, &'a mut ValueTransformer)>
 	{
 		if let Some (AssociatedType {ident, generics, ..}) =
-			AssociatedType::match_type (ty)
+			AssociatedType::match_type_of (ty, forwarded_trait)
 		{
 			self . map . get_mut (&ident) . map
 			(
@@ -1,26 +1,162 @@
-use syn::{Ident, Type, Generics, Fields, Token, parse_quote};
-use syn::parse::Result;
+use syn::{Path, PathArguments, GenericArgument, Type, GenericParam, Token, parse_quote};
+use syn::token::{Paren, Bracket};
+use syn::punctuated::Punctuated;
+use syn::parse::{Result, Error};
+use syn::fold::Fold;
 use syn_derive::{Parse, ToTokens};
 
-use crate::uncurry::get_macro_ident;
+use crate::uncurry::get_macro_path;
+
+use crate::fold::evaluator::get_path_evaluator;
 
 use crate::syn::member::Member;
+use crate::syn::copy_mode::CopyMode;
+use crate::syn::type_def_info::TypeDefInfo;
+use crate::syn::reducers::Reducers;
+
+use crate::composite_transformer::Composition;
 
 use crate::value_transformer
 ::{
 	conversion_transformer::ConversionTransformer,
 	member_transformer::MemberTransformer,
+	element_transformer::ElementTransformer,
 	value_transformer::ValueTransformer
 };
 
 use super::independent_type_transformer::IndependentTypeTransformer;
 
+// If all of a receiver's other members are `PhantomData`, constants of the
+// member type can be turned into constants of the receiver type.  If the
+// receiver is also `#[repr (transparent)]`, it's transparent over the member,
+// and any values can be turned back, including behind references.
+fn get_phantom_members (type_def_info: &TypeDefInfo, member: &Member)
+-> Option <Vec <Member>>
+{
+	member
+		. get_other_members (&type_def_info . fields)
+		. into_iter ()
+		. map
+		(
+			|(other_member, other_type)| match &other_type
+			{
+				Type::Path (type_path)
+				if type_path
+					. path
+					. segments
+					. last ()
+					. is_some_and (|segment| segment . ident == "PhantomData") =>
+					Some (other_member),
+				_ => None
+			}
+		)
+		. collect ()
+}
+
+// Elements of arrays, slices and collections with a single type argument, like
+// `Vec <T>`, can be iterated over.
+fn get_element_type (collection_type: &Type) -> Result <Type>
+{
+	match collection_type
+	{
+		Type::Array (type_array) => return Ok (*type_array . elem . clone ()),
+		Type::Slice (type_slice) => return Ok (*type_slice . elem . clone ()),
+		Type::Path (type_path) =>
+		{
+			if let Some (PathArguments::AngleBracketed (arguments)) = type_path
+				. path
+				. segments
+				. last ()
+				. map (|segment| &segment . arguments)
+			{
+				let mut type_arguments = arguments
+					. args
+					. iter ()
+					. filter_map
+					(
+						|argument| match argument
+						{
+							GenericArgument::Type (ty) => Some (ty),
+							_ => None
+						}
+					);
+
+				if let (Some (element_type), None) =
+					(type_arguments . next (), type_arguments . next ())
+				{
+					return Ok (element_type . clone ());
+				}
+			}
+		},
+		_ => {}
+	}
+
+	Err
+	(
+		Error::new_spanned
+		(
+			collection_type,
+			"Element delegation requires a member that is an array, a slice, or a collection with a single type argument"
+		)
+	)
+}
+
 #[derive (Parse, ToTokens)]
 pub enum BaseTransformType
 {
 	#[parse (peek = Token! [->])]
 	Conversion {arrow_token: Token! [->], to_type: Type},
 
+	#[parse (peek_func = |input| input . fork () . parse::<CopyMode> () . is_ok ())]
+	CopiedConversion
+	{
+		copy_mode: CopyMode,
+		arrow_token: Token! [->],
+		to_type: Type
+	},
+
+	// `. (member, ..)`, calling each method on every one of the members.
+	#[parse (peek_func = |input| input . peek (Token! [.]) && input . peek2 (Paren))]
+	Members
+	{
+		dot_token: Token! [.],
+
+		#[syn (parenthesized)]
+		paren_token: Paren,
+
+		#[syn (in = paren_token)]
+		#[parse (Punctuated::parse_terminated)]
+		members: Punctuated <Member, Token! [,]>,
+
+		reducers: Reducers
+	},
+
+	// `. [member]`, calling each method on every element of the member.
+	#[parse (peek_func = |input| input . peek (Token! [.]) && input . peek2 (Bracket))]
+	Elements
+	{
+		dot_token: Token! [.],
+
+		#[syn (bracketed)]
+		bracket_token: Bracket,
+
+		#[syn (in = bracket_token)]
+		member: Member,
+
+		reducers: Reducers
+	},
+
+	// `. member else member ..`, calling each method on the next member only if
+	// it failed on the ones before it.
+	#[parse (peek_func = |input| input . peek (Token! [.]) && input . peek3 (Token! [else]))]
+	Failover
+	{
+		dot_token: Token! [.],
+
+		#[parse (Punctuated::parse_separated_nonempty)]
+		members: Punctuated <Member, Token! [else]>
+	},
+
 	#[parse (peek = Token! [.])]
 	Member {dot_token: Token! [.], member: Member}
 }
@@ -28,26 +164,153 @@ pub enum BaseTransformType
 #[derive (Parse, ToTokens)]
 pub struct BaseTypeTransformer
 {
-	base_type_ident: Ident,
+	base_type_path: Path,
 	transform_type: BaseTransformType
 }
 
 impl BaseTypeTransformer
 {
-	pub fn get_type_macro_ident (&self) -> Ident
+	// The same receiver, transformed in a different way.
+	pub fn with_transform_type (&self, transform_type: BaseTransformType) -> Self
 	{
-		get_macro_ident (&self . base_type_ident)
+		Self {base_type_path: self . base_type_path . clone (), transform_type}
 	}
 
-	pub fn into_type_transformer
-	(
-		self, base_type_generics:
-		&Generics, fields: &Fields
-	)
+	// Splits a transformation into one transformation for each of the delegates
+	// that it calls methods on, along with how the calls are composed.
+	pub fn split_composite (self) -> Result <(Vec <Self>, Composition)>
+	{
+		let Self {base_type_path, transform_type} = self;
+
+		let split_members = |dot_token, members: Vec <Member>| members
+			. into_iter ()
+			. map
+			(
+				|member| Self
+				{
+					base_type_path: base_type_path . clone (),
+					transform_type: BaseTransformType::Member {dot_token, member}
+				}
+			)
+			. collect ();
+
+		match transform_type
+		{
+			BaseTransformType::Members {dot_token, paren_token, members, reducers} =>
+			{
+				if members . is_empty ()
+				{
+					return Err
+					(
+						Error::new (paren_token . span . join (), "Expected at least one member")
+					);
+				}
+
+				let base_type_transformers =
+					split_members (dot_token, members . into_iter () . collect ());
+
+				Ok ((base_type_transformers, Composition::Broadcast (reducers)))
+			},
+			BaseTransformType::Elements {dot_token, bracket_token, member, reducers} =>
+			{
+				let base_type_transformer = Self
+				{
+					base_type_path: base_type_path . clone (),
+					transform_type: BaseTransformType::Elements
+					{
+						dot_token,
+						bracket_token,
+						member: member . clone (),
+						reducers: Reducers::default ()
+					}
+				};
+
+				Ok ((vec! [base_type_transformer], Composition::Elements (member, reducers)))
+			},
+			BaseTransformType::Failover {dot_token, members} =>
+			{
+				let base_type_transformers =
+					split_members (dot_token, members . into_iter () . collect ());
+
+				Ok ((base_type_transformers, Composition::Failover))
+			},
+			transform_type => Ok
+			(
+				(
+					vec! [Self {base_type_path: base_type_path . clone (), transform_type}],
+					Composition::Broadcast (Reducers::default ())
+				)
+			)
+		}
+	}
+
+	pub fn get_type_macro_path (&self) -> Result <Path>
+	{
+		get_macro_path (&self . base_type_path)
+	}
+
+	// If the receiver is given with generic arguments, they're substituted into
+	// its definition, which leaves only the remaining generic parameters (none)
+	// to be declared on the forwarded implementations.  Otherwise, the
+	// receiver's parameters are kept, without their defaults.
+	pub fn instantiate (&self, type_def_info: TypeDefInfo) -> Result <TypeDefInfo>
+	{
+		let TypeDefInfo {repr_transparent, type_token, mut generics, fields} =
+			type_def_info;
+
+		let has_arguments = self
+			. base_type_path
+			. segments
+			. last ()
+			. is_some_and (|segment| ! matches! (segment . arguments, PathArguments::None));
+
+		if ! has_arguments
+		{
+			for param in &mut generics . params
+			{
+				match param
+				{
+					GenericParam::Type (type_param) =>
+					{
+						type_param . eq_token = None;
+						type_param . default = None;
+					},
+					GenericParam::Const (const_param) =>
+					{
+						const_param . eq_token = None;
+						const_param . default = None;
+					},
+					GenericParam::Lifetime (_) => {}
+				}
+			}
+
+			return Ok (TypeDefInfo {repr_transparent, type_token, generics, fields});
+		}
+
+		let where_clause = generics . where_clause . take ();
+
+		let mut evaluator =
+			get_path_evaluator (generics . clone (), &self . base_type_path, "Type")?;
+
+		generics . params . clear ();
+		generics . lt_token = None;
+		generics . gt_token = None;
+		generics . where_clause = where_clause
+			. map (|where_clause| evaluator . fold_where_clause (where_clause));
+
+		let fields = evaluator . fold_fields (fields);
+
+		Ok (TypeDefInfo {repr_transparent, type_token, generics, fields})
+	}
+
+	pub fn into_type_transformer (self, type_def_info: &TypeDefInfo)
 	-> Result <(Type, Type, IndependentTypeTransformer)>
 	{
-		let base_type_ident = &self . base_type_ident;
-		let base_type: Type = parse_quote! (#base_type_ident #base_type_generics);
+		let TypeDefInfo {generics: base_type_generics, fields, ..} = type_def_info;
+
+		let base_type_path = &self . base_type_path;
+		let (_, base_type_generics, _) = base_type_generics . split_for_impl ();
+		let base_type: Type = parse_quote! (#base_type_path #base_type_generics);
 
 		let from_type: Type = parse_quote! (Self);
 
@@ -58,10 +321,54 @@ impl BaseTypeTransformer
 				to_type,
 				ValueTransformer::from (ConversionTransformer::new ())
 			),
+			BaseTransformType::CopiedConversion {copy_mode, to_type, ..} =>
+			(
+				to_type,
+				ValueTransformer::from (ConversionTransformer::new_copied (copy_mode))
+			),
 			BaseTransformType::Member {member, ..} =>
+			{
+				let member_type = member . get_member_type (fields)?;
+				let pinned = member . is_pinned (fields);
+
+				let mut member_transformer = match get_phantom_members
+				(
+					type_def_info,
+					&member
+				)
+				{
+					Some (phantom_members) if type_def_info . repr_transparent =>
+						MemberTransformer::new_transparent (member, phantom_members),
+					Some (phantom_members) =>
+						MemberTransformer::new_reconstructible (member, phantom_members),
+					None => MemberTransformer::new (member)
+				};
+
+				member_transformer . set_pinned (pinned);
+
+				(member_type, ValueTransformer::from (member_transformer))
+			},
+			BaseTransformType::Elements {member, ..} =>
+			{
+				let element_type = get_element_type (&member . get_member_type (fields)?)?;
+
+				(element_type, ValueTransformer::from (ElementTransformer::new ()))
+			},
+			BaseTransformType::Members {members, ..} => return Err
+			(
+				Error::new_spanned
+				(
+					members,
+					"Forwarding to several members must be split into forwarding to each member"
+				)
+			),
+			BaseTransformType::Failover {members, ..} => return Err
 			(
-				member . get_member_type (fields)?,
-				ValueTransformer::from (MemberTransformer::new (member))
+				Error::new_spanned
+				(
+					members,
+					"Forwarding to several members must be split into forwarding to each member"
+				)
 			)
 		};
 
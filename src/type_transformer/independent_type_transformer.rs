@@ -40,21 +40,23 @@ impl IndependentTypeTransformers
 		);
 	}
 
+	pub fn get_to_type (&self, ty: &Type) -> Option <Type>
+	{
+		self
+			. map
+			. get (ty)
+			. map
+			(
+				|independent_type_transformer|
+				independent_type_transformer . to_type . clone ()
+			)
+	}
+
 	// Of the fold variety.
 	pub fn get_type_transformer (&self)
 	-> TypeTransformer <impl FnMut (&Type) -> Option <Type> + '_>
 	{
-		TypeTransformer::new
-		(
-			|ty| self
-				. map
-				. get (ty)
-				. map
-				(
-					|independent_type_transformer|
-					independent_type_transformer . to_type . clone ()
-				)
-		)
+		TypeTransformer::new (|ty| self . get_to_type (ty))
 	}
 
 	pub fn get_transformation <'a, 'b> (&'a mut self, ty: &'b Type)
@@ -1,4 +1,7 @@
-use syn::{Attribute, Visibility, Path, Ident, parse_quote};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use syn::{Attribute, Visibility, Path, Ident, ItemImpl, parse_quote};
 use syn::parse::{Result, Error};
 use quote::{quote, ToTokens, format_ident};
 
@@ -9,6 +12,23 @@ pub fn get_macro_ident (ident: &Ident) -> Ident
 	format_ident! ("uncurry_trait_forwarding_info_for_{}", ident)
 }
 
+pub fn get_inherent_macro_ident (ident: &Ident) -> Ident
+{
+	format_ident! ("uncurry_inherent_forwarding_info_for_{}", ident)
+}
+
+// Exported macros all share the crate root, where the macros of impl blocks of
+// types of the same name in different modules would collide, so their mangled
+// names are made unique by a hash of the impl block and its location.
+pub fn get_inherent_mangled_ident (macro_ident: &Ident, item_impl: &ItemImpl) -> Ident
+{
+	let mut hasher = DefaultHasher::new ();
+	item_impl . to_token_stream () . to_string () . hash (&mut hasher);
+	format! ("{:?}", item_impl . impl_token . span) . hash (&mut hasher);
+
+	format_ident! ("__{}_{:016x}__", macro_ident, hasher . finish ())
+}
+
 pub fn get_path_ident (path: &Path) -> Result <Ident>
 {
 	match path . segments . last ()
@@ -22,6 +42,17 @@ pub fn get_path_ident (path: &Path) -> Result <Ident>
 }
 
 pub fn get_macro_path (path: &Path) -> Result <Path>
+{
+	replace_path_ident (path, get_macro_ident)
+}
+
+pub fn get_inherent_macro_path (path: &Path) -> Result <Path>
+{
+	replace_path_ident (path, get_inherent_macro_ident)
+}
+
+fn replace_path_ident (path: &Path, get_macro_ident: fn (&Ident) -> Ident)
+-> Result <Path>
 {
 	let ident = get_path_ident (path)?;
 	let macro_ident = get_macro_ident (&ident);
@@ -43,6 +74,20 @@ where T: ToTokens
 {
 	let mangled_ident = mangle_ident (&macro_ident);
 
+	gen_uncurry_macro_as (visibility, macro_ident, mangled_ident, injected_data)
+}
+
+// The mangled name is the one that the macro is exported under, if it's public.
+pub fn gen_uncurry_macro_as <T>
+(
+	visibility: Visibility,
+	macro_ident: Ident,
+	mangled_ident: Ident,
+	injected_data: T
+)
+-> proc_macro2::TokenStream
+where T: ToTokens
+{
 	let export_attribute: Option <Attribute> = match visibility
 	{
 		Visibility::Public (_) => Some (parse_quote! (#[macro_export])),
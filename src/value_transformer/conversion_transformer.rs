@@ -1,13 +1,22 @@
-use syn::{Type, Expr, WherePredicate, BoundLifetimes, Token, parse_quote};
+use syn::{Type, Expr, Path, WherePredicate, BoundLifetimes, Token, parse_quote};
 use syn::punctuated::Punctuated;
-use syn::Result;
+use syn::{Result, Error};
+
+use crate::syn::copy_mode::CopyMode;
 
 pub struct ConversionTransformer
 {
+	copy_mode: Option <CopyMode>,
+
 	use_into: bool,
 	use_borrow: bool,
 	use_borrow_mut: bool,
 	use_from: bool,
+	use_copy: bool,
+	use_unpin: bool,
+	use_unpin_receiver: bool,
+	use_clone: bool,
+	use_clone_receiver: bool
 }
 
 impl ConversionTransformer
@@ -16,10 +25,51 @@ impl ConversionTransformer
 	{
 		Self
 		{
+			copy_mode: None,
 			use_into: false,
 			use_borrow: false,
 			use_borrow_mut: false,
 			use_from: false,
+			use_copy: false,
+			use_unpin: false,
+			use_unpin_receiver: false,
+			use_clone: false,
+			use_clone_receiver: false
+		}
+	}
+
+	// Borrowed receivers are converted from a copy of the receiver instead of
+	// through `AsRef` and `AsMut`.
+	pub fn new_copied (copy_mode: CopyMode) -> Self
+	{
+		Self {copy_mode: Some (copy_mode), .. Self::new ()}
+	}
+
+	pub fn copies (&self) -> bool
+	{
+		self . copy_mode . is_some ()
+	}
+
+	fn reject_copies (&self, from_type: &Type, message: &str) -> Result <()>
+	{
+		match self . copy_mode
+		{
+			Some (_) => Err (Error::new_spanned (from_type, message)),
+			None => Ok (())
+		}
+	}
+
+	fn copy (&mut self, input: Expr, from_type: &Type) -> Expr
+	{
+		self . use_copy = true;
+
+		match self . copy_mode
+		{
+			Some (CopyMode::Clone (_)) => parse_quote!
+			(
+				<#from_type as std::clone::Clone>::clone (#input)
+			),
+			_ => parse_quote! (*#input)
 		}
 	}
 }
@@ -54,6 +104,15 @@ impl ConversionTransformer
 	)
 	-> Result <Expr>
 	{
+		if self . copies ()
+		{
+			let copied_input = self . copy (input, from_type);
+			let converted_input =
+				self . transform_input (copied_input, from_type, to_type)?;
+
+			return Ok (parse_quote! (&#converted_input));
+		}
+
 		self . use_borrow = true;
 
 		let input = parse_quote!
@@ -83,6 +142,99 @@ impl ConversionTransformer
 		Ok (input)
 	}
 
+	pub fn transform_input_pin_ref
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . reject_copies (from_type, "Pinned values cannot be copied for forwarding")?;
+
+		self . use_borrow = true;
+		self . use_unpin = true;
+
+		let input = parse_quote!
+		(
+			std::pin::Pin::new
+			(
+				<#from_type as std::convert::AsRef <#to_type>>::as_ref
+				(
+					std::pin::Pin::get_ref (#input)
+				)
+			)
+		);
+
+		Ok (input)
+	}
+
+	pub fn transform_input_pin_ref_mut
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . reject_copies (from_type, "Pinned values cannot be copied for forwarding")?;
+
+		self . use_borrow_mut = true;
+		self . use_unpin = true;
+		self . use_unpin_receiver = true;
+
+		let input = parse_quote!
+		(
+			std::pin::Pin::new
+			(
+				<#from_type as std::convert::AsMut <#to_type>>::as_mut
+				(
+					std::pin::Pin::get_mut (#input)
+				)
+			)
+		);
+
+		Ok (input)
+	}
+
+	// Shared pointers can't be converted in place, so the value is taken out of
+	// the pointer (or cloned if it's still shared), converted, and put into a
+	// new allocation.
+	pub fn transform_input_shared
+	(
+		&mut self,
+		input: Expr,
+		shared_path: &Path,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . use_clone_receiver = true;
+
+		let unwrapped_input = parse_quote! (#shared_path::unwrap_or_clone (#input));
+		let converted_input =
+			self . transform_input (unwrapped_input, from_type, to_type)?;
+
+		Ok (parse_quote! (#shared_path::new (#converted_input)))
+	}
+
+	pub fn transform_input_copy_in
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		let copied_input = self . copy (input, from_type);
+
+		self . transform_input (copied_input, from_type, to_type)
+	}
+
 	pub fn transform_output
 	(
 		&mut self,
@@ -102,6 +254,25 @@ impl ConversionTransformer
 		Ok (output)
 	}
 
+	pub fn transform_output_shared
+	(
+		&mut self,
+		output: Expr,
+		shared_path: &Path,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . use_clone = true;
+
+		let unwrapped_output = parse_quote! (#shared_path::unwrap_or_clone (#output));
+		let converted_output =
+			self . transform_output (unwrapped_output, from_type, to_type)?;
+
+		Ok (parse_quote! (#shared_path::new (#converted_output)))
+	}
+
 	pub fn add_predicates
 	(
 		&self,
@@ -142,5 +313,49 @@ impl ConversionTransformer
 				parse_quote! (#lifetimes #from_type: std::convert::From <#to_type>)
 			);
 		}
+
+		if self . use_unpin
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #to_type: std::marker::Unpin)
+			);
+		}
+
+		if self . use_unpin_receiver
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #from_type: std::marker::Unpin)
+			);
+		}
+
+		if self . use_clone
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #to_type: std::clone::Clone)
+			);
+		}
+
+		if self . use_clone_receiver
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #from_type: std::clone::Clone)
+			);
+		}
+
+		if self . use_copy
+		{
+			let predicate = match self . copy_mode
+			{
+				Some (CopyMode::Clone (_)) =>
+					parse_quote! (#lifetimes #from_type: std::clone::Clone),
+				_ => parse_quote! (#lifetimes #from_type: std::marker::Copy)
+			};
+
+			predicates . push (predicate);
+		}
 	}
 }
@@ -0,0 +1,59 @@
+use syn::{Type, Expr, Ident, parse_quote};
+use syn::parse::{Result, Error};
+use proc_macro2::Span;
+
+// Each element of a collection member stands in for the receiver in the calls
+// made while iterating over the collection.  Other values of the receiver type
+// have no element to stand in for them.
+pub struct ElementTransformer {}
+
+impl ElementTransformer
+{
+	pub fn new () -> Self
+	{
+		Self {}
+	}
+
+	pub fn get_element_ident () -> Ident
+	{
+		Ident::new ("element", Span::mixed_site ())
+	}
+
+	pub fn transform_input (&self, input: Expr, from_type: &Type) -> Result <Expr>
+	{
+		match &input
+		{
+			Expr::Path (expr_path) if expr_path . path . is_ident ("self") =>
+			{
+				let element_ident = Self::get_element_ident ();
+				Ok (parse_quote! (#element_ident))
+			},
+			_ => Err
+			(
+				Error::new_spanned
+				(
+					from_type,
+					"Element delegation can only transform the receiver, as other values of the receiver type have no element to stand in for them"
+				)
+			)
+		}
+	}
+
+	pub fn reject_input (&self, from_type: &Type) -> Error
+	{
+		Error::new_spanned
+		(
+			from_type,
+			"Element delegation can only transform receivers taken by value or by reference"
+		)
+	}
+
+	pub fn reject_output (&self, from_type: &Type) -> Error
+	{
+		Error::new_spanned
+		(
+			from_type,
+			"Element delegation cannot transform values of the delegated type back into the receiver type"
+		)
+	}
+}
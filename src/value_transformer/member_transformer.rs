@@ -1,18 +1,95 @@
-use syn::{Expr, parse_quote};
-use syn::parse::Result;
+use syn::{Type, Expr, Path, WherePredicate, BoundLifetimes, Token, parse_quote};
+use syn::punctuated::Punctuated;
+use syn::parse::{Result, Error};
 
 use crate::syn::member::Member;
 
 pub struct MemberTransformer
 {
-	member: Member
+	member: Member,
+
+	// Only present when every other member of the receiver is a `PhantomData`,
+	// in which case it lists the members that must be filled in when
+	// reconstructing the receiver.
+	phantom_members: Option <Vec <Member>>,
+
+	// Whether the receiver is also `#[repr (transparent)]`, so that it shares
+	// its layout with the member.
+	transparent: bool,
+
+	// Whether the member is marked with `#[pin]` on the receiver, and so may be
+	// projected structurally.
+	pinned: bool,
+
+	use_unpin: bool,
+	use_clone: bool
 }
 
 impl MemberTransformer
 {
 	pub fn new (member: Member) -> Self
 	{
-		Self {member}
+		Self
+		{
+			member,
+			phantom_members: None,
+			transparent: false,
+			pinned: false,
+			use_unpin: false,
+			use_clone: false
+		}
+	}
+
+	pub fn new_reconstructible (member: Member, phantom_members: Vec <Member>)
+	-> Self
+	{
+		Self {phantom_members: Some (phantom_members), .. Self::new (member)}
+	}
+
+	pub fn new_transparent (member: Member, phantom_members: Vec <Member>)
+	-> Self
+	{
+		Self
+		{
+			transparent: true,
+			.. Self::new_reconstructible (member, phantom_members)
+		}
+	}
+
+	pub fn set_pinned (&mut self, pinned: bool)
+	{
+		self . pinned = pinned;
+	}
+
+	pub fn is_transparent (&self) -> bool
+	{
+		self . transparent
+	}
+
+	fn require_reconstructible (&self, from_type: &Type) -> Result <&Vec <Member>>
+	{
+		self . phantom_members . as_ref () . ok_or_else
+		(
+			|| Error::new_spanned
+			(
+				from_type,
+				"Member delegation can only transform constants of the delegated type into the receiver type if every other member of the receiver is `PhantomData`"
+			)
+		)
+	}
+
+	fn require_transparent (&self, from_type: &Type) -> Result <()>
+	{
+		if self . transparent { return Ok (()); }
+
+		Err
+		(
+			Error::new_spanned
+			(
+				from_type,
+				"Member delegation can only transform values of the delegated type back into the receiver type if the receiver is `#[repr (transparent)]`"
+			)
+		)
 	}
 }
 
@@ -50,4 +127,256 @@ impl MemberTransformer
 		let member = &self . member;
 		Ok (parse_quote! (&mut #input . #member))
 	}
+
+	pub fn transform_input_pin_ref
+	(
+		&mut self,
+		input: Expr
+	)
+	-> Result <Expr>
+	{
+		let member = &self . member;
+
+		if self . pinned
+		{
+			return Ok
+			(
+				parse_quote!
+				(
+					unsafe
+					{
+						std::pin::Pin::map_unchecked (#input, |receiver| &receiver . #member)
+					}
+				)
+			);
+		}
+
+		self . use_unpin = true;
+
+		Ok
+		(
+			parse_quote!
+			(
+				std::pin::Pin::new (&std::pin::Pin::get_ref (#input) . #member)
+			)
+		)
+	}
+
+	pub fn transform_input_pin_ref_mut
+	(
+		&mut self,
+		input: Expr
+	)
+	-> Result <Expr>
+	{
+		let member = &self . member;
+
+		if self . pinned
+		{
+			return Ok
+			(
+				parse_quote!
+				(
+					unsafe
+					{
+						std::pin::Pin::map_unchecked_mut
+						(
+							#input,
+							|receiver| &mut receiver . #member
+						)
+					}
+				)
+			);
+		}
+
+		// The member is `Unpin`, so it isn't pinned structurally, and may be
+		// borrowed mutably without pinning the rest of the receiver.
+		self . use_unpin = true;
+
+		Ok
+		(
+			parse_quote!
+			(
+				std::pin::Pin::new
+				(
+					&mut unsafe { std::pin::Pin::get_unchecked_mut (#input) } . #member
+				)
+			)
+		)
+	}
+
+	pub fn transform_input_shared
+	(
+		&mut self,
+		input: Expr,
+		shared_path: &Path,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		// A transparent receiver shares its layout with the member, so the
+		// allocation can be reused, and is still shared afterwards.
+		if self . is_transparent ()
+		{
+			return Ok
+			(
+				parse_quote!
+				(
+					unsafe
+					{
+						#shared_path::from_raw
+						(
+							#shared_path::into_raw (#input) as *const #to_type
+						)
+					}
+				)
+			);
+		}
+
+		// Otherwise the member is cloned out of the receiver.  It can't be
+		// moved out of a unique receiver, as the receiver type may implement
+		// `Drop`.
+		self . use_clone = true;
+
+		let member = &self . member;
+
+		Ok
+		(
+			parse_quote!
+			(
+				#shared_path::new
+				(
+					std::clone::Clone::clone (&(#input) . #member)
+				)
+			)
+		)
+	}
+
+	pub fn transform_output
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . require_transparent (from_type)?;
+
+		self . transform_output_const (output, from_type)
+	}
+
+	// Constants don't come from a receiver in the first place, so they're
+	// rebuilt whenever the other members can be filled in, transparent or not.
+	pub fn transform_output_const
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type
+	)
+	-> Result <Expr>
+	{
+		let phantom_members = self . require_reconstructible (from_type)?;
+		let member = &self . member;
+
+		let output = parse_quote!
+		(
+			#from_type
+			{
+				#member: #output,
+				#(#phantom_members: std::marker::PhantomData),*
+			}
+		);
+
+		Ok (output)
+	}
+
+	pub fn transform_output_ref
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . require_transparent (from_type)?;
+
+		// The receiver is `#[repr (transparent)]` over the delegated type, so
+		// the two share a layout.
+		let output = parse_quote!
+		(
+			unsafe { &*(#output as *const #to_type as *const #from_type) }
+		);
+
+		Ok (output)
+	}
+
+	pub fn transform_output_ref_mut
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . require_transparent (from_type)?;
+
+		let output = parse_quote!
+		(
+			unsafe { &mut *(#output as *mut #to_type as *mut #from_type) }
+		);
+
+		Ok (output)
+	}
+
+	pub fn transform_output_shared
+	(
+		&mut self,
+		output: Expr,
+		shared_path: &Path,
+		from_type: &Type
+	)
+	-> Result <Expr>
+	{
+		self . require_transparent (from_type)?;
+
+		let output = parse_quote!
+		(
+			unsafe
+			{
+				#shared_path::from_raw
+				(
+					#shared_path::into_raw (#output) as *const #from_type
+				)
+			}
+		);
+
+		Ok (output)
+	}
+
+	pub fn add_predicates
+	(
+		&self,
+		predicates: &mut Punctuated <WherePredicate, Token! [,]>,
+		lifetimes: &Option <BoundLifetimes>,
+		to_type: &Type
+	)
+	{
+		if self . use_unpin
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #to_type: std::marker::Unpin)
+			);
+		}
+
+		if self . use_clone
+		{
+			predicates . push
+			(
+				parse_quote! (#lifetimes #to_type: std::clone::Clone)
+			);
+		}
+	}
 }
@@ -1,4 +1,5 @@
 pub mod conversion_transformer;
 pub mod member_transformer;
+pub mod element_transformer;
 
 pub mod value_transformer;
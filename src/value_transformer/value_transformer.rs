@@ -1,14 +1,16 @@
-use syn::{Type, Expr, WherePredicate, BoundLifetimes, Token};
+use syn::{Type, Expr, Path, WherePredicate, BoundLifetimes, Token};
 use syn::punctuated::Punctuated;
 use syn::parse::{Result, Error};
 
 use super::conversion_transformer::ConversionTransformer;
 use super::member_transformer::MemberTransformer;
+use super::element_transformer::ElementTransformer;
 
 pub enum ValueTransformer
 {
 	Conversion (ConversionTransformer),
-	Member (MemberTransformer)
+	Member (MemberTransformer),
+	Element (ElementTransformer)
 }
 
 impl From <ConversionTransformer> for ValueTransformer
@@ -27,6 +29,14 @@ impl From <MemberTransformer> for ValueTransformer
 	}
 }
 
+impl From <ElementTransformer> for ValueTransformer
+{
+	fn from (element_transformer: ElementTransformer) -> Self
+	{
+		Self::Element (element_transformer)
+	}
+}
+
 impl ValueTransformer
 {
 	pub fn transform_input
@@ -43,7 +53,9 @@ impl ValueTransformer
 			Self::Conversion (conversion_transformer) => conversion_transformer
 				. transform_input (input, from_type, to_type),
 			Self::Member (member_transformer) => member_transformer
-				. transform_input (input)
+				. transform_input (input),
+			Self::Element (element_transformer) => element_transformer
+				. transform_input (input, from_type)
 		}
 	}
 
@@ -61,7 +73,9 @@ impl ValueTransformer
 			Self::Conversion (conversion_transformer) => conversion_transformer
 				. transform_input_ref (input, from_type, to_type),
 			Self::Member (member_transformer) => member_transformer
-				. transform_input_ref (input)
+				. transform_input_ref (input),
+			Self::Element (element_transformer) => element_transformer
+				. transform_input (input, from_type)
 		}
 	}
 
@@ -79,7 +93,113 @@ impl ValueTransformer
 			Self::Conversion (conversion_transformer) => conversion_transformer
 				. transform_input_ref_mut (input, from_type, to_type),
 			Self::Member (member_transformer) => member_transformer
-				. transform_input_ref_mut (input)
+				. transform_input_ref_mut (input),
+			Self::Element (element_transformer) => element_transformer
+				. transform_input (input, from_type)
+		}
+	}
+
+	pub fn transform_input_shared
+	(
+		&mut self,
+		input: Expr,
+		shared_path: &Path,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. transform_input_shared (input, shared_path, from_type, to_type),
+			Self::Member (member_transformer) => member_transformer
+				. transform_input_shared (input, shared_path, to_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_input (from_type))
+		}
+	}
+
+	pub fn transform_input_pin_ref
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. transform_input_pin_ref (input, from_type, to_type),
+			Self::Member (member_transformer) => member_transformer
+				. transform_input_pin_ref (input),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_input (from_type))
+		}
+	}
+
+	pub fn transform_input_pin_ref_mut
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. transform_input_pin_ref_mut (input, from_type, to_type),
+			Self::Member (member_transformer) => member_transformer
+				. transform_input_pin_ref_mut (input),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_input (from_type))
+		}
+	}
+
+	pub fn copies (&self) -> bool
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. copies (),
+			Self::Member (_member_transformer) => false,
+			Self::Element (_element_transformer) => false
+		}
+	}
+
+	pub fn transform_input_copy_in
+	(
+		&mut self,
+		input: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. transform_input_copy_in (input, from_type, to_type),
+			Self::Member (_member_transformer) => Err
+			(
+				Error::new_spanned
+				(
+					from_type,
+					"Member delegation does not copy values"
+				)
+			),
+			Self::Element (_element_transformer) => Err
+			(
+				Error::new_spanned
+				(
+					from_type,
+					"Element delegation does not copy values"
+				)
+			)
 		}
 	}
 
@@ -96,14 +216,119 @@ impl ValueTransformer
 		{
 			Self::Conversion (conversion_transformer) => conversion_transformer
 				. transform_output (output, from_type, to_type),
-			Self::Member (_member_transformer) => Err
+			Self::Member (member_transformer) => member_transformer
+				. transform_output (output, from_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_output (from_type))
+		}
+	}
+
+	pub fn transform_output_shared
+	(
+		&mut self,
+		output: Expr,
+		shared_path: &Path,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (conversion_transformer) => conversion_transformer
+				. transform_output_shared (output, shared_path, from_type, to_type),
+			Self::Member (member_transformer) => member_transformer
+				. transform_output_shared (output, shared_path, from_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_output (from_type))
+		}
+	}
+
+	pub fn transform_output_const
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (_conversion_transformer) => Err
 			(
 				Error::new_spanned
 				(
 					from_type,
-					"Member delegation cannot transform return values for forwarding"
+					"Conversion delegation cannot transform associated constants, as `From::from` can't be called in constant expressions"
 				)
-			)
+			),
+			Self::Member (member_transformer) => member_transformer
+				. transform_output_const (output, from_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_output (from_type))
+		}
+	}
+
+	pub fn transform_output_ref
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (_conversion_transformer) => Err
+			(
+				Error::new_spanned
+				(
+					from_type,
+					"Conversion delegation cannot transform borrowed values of the delegated type back into the receiver type"
+				)
+			),
+			Self::Member (member_transformer) => member_transformer
+				. transform_output_ref (output, from_type, to_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_output (from_type))
+		}
+	}
+
+	pub fn transform_output_ref_mut
+	(
+		&mut self,
+		output: Expr,
+		from_type: &Type,
+		to_type: &Type
+	)
+	-> Result <Expr>
+	{
+		match self
+		{
+			Self::Conversion (_conversion_transformer) => Err
+			(
+				Error::new_spanned
+				(
+					from_type,
+					"Conversion delegation cannot transform borrowed values of the delegated type back into the receiver type"
+				)
+			),
+			Self::Member (member_transformer) => member_transformer
+				. transform_output_ref_mut (output, from_type, to_type),
+			Self::Element (element_transformer) =>
+				Err (element_transformer . reject_output (from_type))
+		}
+	}
+
+	pub fn is_transparent (&self) -> bool
+	{
+		match self
+		{
+			Self::Conversion (_conversion_transformer) => false,
+			Self::Member (member_transformer) => member_transformer
+				. is_transparent (),
+			Self::Element (_element_transformer) => false
 		}
 	}
 
@@ -120,7 +345,9 @@ impl ValueTransformer
 		{
 			Self::Conversion (conversion_transformer) => conversion_transformer
 				. add_predicates (predicates, lifetimes, from_type, to_type),
-			Self::Member (_member_transformer) => {}
+			Self::Member (member_transformer) => member_transformer
+				. add_predicates (predicates, lifetimes, to_type),
+			Self::Element (_element_transformer) => {}
 		}
 	}
 }